@@ -0,0 +1,89 @@
+//! Checks that interpolation leaves the z coordinate of rendered entities untouched, so it can be used for draw ordering.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_2d::{math::Vector, prelude::*};
+use bevy_xpbd_2d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so most frames blend between two steps.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn keep_z_coordinate() {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ));
+
+    let body = app
+        .world
+        .spawn((
+            RigidBody::Kinematic,
+            LinearVelocity(Vector::X),
+            AngularVelocity(1.0),
+        ))
+        .id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::from_transform(Transform::from_xyz(0.0, 0.0, 5.0)),
+            InterpolatedPosition::from_source(body),
+        ))
+        .id();
+    let parent = app
+        .world
+        .spawn(TransformBundle::from_transform(
+            Transform::from_xyz(3.0, 1.0, 2.0).with_rotation(Quat::from_rotation_z(0.5)),
+        ))
+        .id();
+    let child = app
+        .world
+        .spawn((
+            TransformBundle::from_transform(Transform::from_xyz(0.0, 0.0, -1.5)),
+            InterpolatedTransform::from_source(body),
+        ))
+        .set_parent(parent)
+        .id();
+
+    for frame in 0..20 {
+        app.update();
+
+        let translation = app.world.get::<Transform>(render).unwrap().translation;
+        assert_eq!(translation.z, 5.0, "z in frame {frame}");
+
+        // The child is rendered at the same position as the entity without a parent, with its own z.
+        let child_translation = app.world.get::<Transform>(child).unwrap().translation;
+        assert!(
+            (child_translation.z + 1.5).abs() < EPSILON,
+            "z of the child {} in frame {}",
+            child_translation.z,
+            frame
+        );
+        let world_translation = app
+            .world
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .translation();
+        assert!(
+            world_translation
+                .truncate()
+                .abs_diff_eq(translation.truncate(), EPSILON),
+            "child at {} in frame {}, expected {}",
+            world_translation,
+            frame,
+            translation
+        );
+    }
+
+    // The body moved, so the rendered entities were interpolated.
+    assert!(app.world.get::<Transform>(render).unwrap().translation.x > 0.0);
+}
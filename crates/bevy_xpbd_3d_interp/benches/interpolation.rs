@@ -0,0 +1,104 @@
+// This benchmark measures the time spent caching and interpolating large numbers of rigidbodies,
+// comparing processing all entities in a single batch to splitting them into batches processed in parallel.
+// Run it with `cargo bench -p bevy_xpbd_3d_interp`.
+
+use std::time::Duration;
+
+use bevy::{
+    diagnostic::{DiagnosticPath, DiagnosticsPlugin, DiagnosticsStore},
+    ecs::query::BatchingStrategy,
+    prelude::*,
+    scene::ScenePlugin,
+    time::TimeUpdateStrategy,
+};
+use bevy_xpbd_3d::{
+    math::{Scalar, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+const ENTITY_COUNTS: [usize; 3] = [1_000, 10_000, 20_000];
+const WARMUP_FRAMES: usize = 20;
+const MEASURED_FRAMES: usize = 100;
+
+fn main() {
+    println!(
+        "{:>8} | {:>12} | {:>14} | {:>20}",
+        "entities", "strategy", "copy time (ms)", "interpolation time (ms)"
+    );
+    for entity_count in ENTITY_COUNTS {
+        for (name, strategy) in [
+            ("single batch", BatchingStrategy::fixed(entity_count)),
+            ("parallel", BatchingStrategy::new()),
+        ] {
+            let (copy_time, interpolation_time) = run(entity_count, strategy);
+            println!(
+                "{:>8} | {:>12} | {:>14.3} | {:>20.3}",
+                entity_count, name, copy_time, interpolation_time
+            );
+        }
+    }
+}
+
+/// Returns the average time spent caching and interpolating per frame in milliseconds.
+fn run(entity_count: usize, strategy: BatchingStrategy) -> (f64, f64) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ScenePlugin,
+        DiagnosticsPlugin,
+        TransformPlugin,
+        HierarchyPlugin,
+        PhysicsPlugins::default(),
+        XPBDInterpolationPlugin::default().with_batching_strategy(strategy),
+    ))
+    .add_plugins(InterpolationDiagnosticsPlugin)
+    .init_asset::<Mesh>()
+    // Physics steps every frame, so both the copy and interpolation systems run every frame.
+    .insert_resource(Time::new_with(Physics::fixed_hz(60.0)))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / 60.0,
+    )));
+
+    for i in 0..entity_count {
+        let physics_entity = app
+            .world
+            .spawn((
+                RigidBody::Kinematic,
+                Position(Vector::new(i as Scalar, 0.0, 0.0)),
+                LinearVelocity(Vector::Y),
+                AngularVelocity(Vector::Z),
+            ))
+            .id();
+        app.world.spawn((
+            TransformBundle::default(),
+            InterpolatedPosition::from_source(physics_entity),
+            InterpolatedRotation::from_source(physics_entity),
+        ));
+    }
+
+    for _ in 0..WARMUP_FRAMES {
+        app.update();
+    }
+    let mut copy_time = 0.0;
+    let mut interpolation_time = 0.0;
+    for _ in 0..MEASURED_FRAMES {
+        app.update();
+        copy_time += measurement(&app, &InterpolationDiagnosticsPlugin::COPY_TIME);
+        interpolation_time +=
+            measurement(&app, &InterpolationDiagnosticsPlugin::INTERPOLATION_TIME);
+    }
+
+    (
+        copy_time / MEASURED_FRAMES as f64,
+        interpolation_time / MEASURED_FRAMES as f64,
+    )
+}
+
+fn measurement(app: &App, path: &DiagnosticPath) -> f64 {
+    app.world
+        .resource::<DiagnosticsStore>()
+        .get_measurement(path)
+        .map_or(0.0, |measurement| measurement.value)
+}
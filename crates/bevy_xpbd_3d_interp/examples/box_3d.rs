@@ -1,7 +1,7 @@
 // This example sets up a rigidbody that is rotated and moved by bevy_xpbd, and a separate entity with a rendered box and InterpolatedPosition/Rotation components.
 
 use bevy::prelude::*;
-use bevy_xpbd_3d::prelude::*;
+use bevy_xpbd_3d::{math::Vector, prelude::*};
 use bevy_xpbd_3d_interp::prelude::*;
 
 const PHYSICS_UPDATE_FREQ: f64 = 10.0;
@@ -11,10 +11,9 @@ fn main() {
         .add_plugins((
             DefaultPlugins,
             PhysicsPlugins::default(),
-            XPBDInterpolationPlugin,
+            XPBDInterpolationPlugin::default(),
         ))
         .insert_resource(Time::new_with(Physics::fixed_hz(PHYSICS_UPDATE_FREQ)))
-        .insert_resource(IsInterpolating(true)) // Has no effect on actual interpolation, is just for ui
         .add_systems(Startup, setup)
         .add_systems(Update, (update_box, toggle_interpolation, update_ui))
         .run();
@@ -91,7 +90,7 @@ fn update_box(
 ) {
     let speed = 4.;
     for (mut angular_velocity, mut linear_velocity) in box_q.iter_mut() {
-        let mut velocity = Vec3::ZERO;
+        let mut velocity = Vector::ZERO;
 
         if keyboard_input.pressed(KeyCode::KeyW) {
             velocity.y += speed;
@@ -108,30 +107,28 @@ fn update_box(
 
         linear_velocity.0 = velocity;
 
-        angular_velocity.0 = Vec3::from_array([1., 1.5, 2.]) * 0.4;
+        angular_velocity.0 = Vector::from_array([1., 1.5, 2.]) * 0.4;
     }
 }
 
-// IsInterpolating has no effect on actual interpolation and is just for ui.
-// Toggle interpolation with InterpolatedPosition::pass_raw and InterpolatedRotation::pass_raw instead.
-#[derive(Resource)]
-struct IsInterpolating(bool);
-
 fn toggle_interpolation(
-    mut interp_q: Query<(&mut InterpolatedPosition, &mut InterpolatedRotation)>,
-    mut is_interpolating: ResMut<IsInterpolating>,
+    mut settings: ResMut<InterpolationSettings>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Space) {
-        let (mut pos, mut rot) = interp_q.single_mut();
-        pos.pass_raw = !pos.pass_raw;
-        rot.pass_raw = !rot.pass_raw;
+        settings.enabled = !settings.enabled;
+    }
 
-        is_interpolating.0 = !pos.pass_raw;
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        settings.default_mode = match settings.default_mode {
+            InterpolationMode::Interpolate => InterpolationMode::Hermite,
+            InterpolationMode::Hermite => InterpolationMode::Extrapolate { max_time: 0.2 },
+            InterpolationMode::Extrapolate { .. } => InterpolationMode::Interpolate,
+        };
     }
 }
 
-fn update_ui(mut text: Query<&mut Text>, is_interpolating: Res<IsInterpolating>) {
+fn update_ui(mut text: Query<&mut Text>, settings: Res<InterpolationSettings>) {
     let mut text = text.single_mut();
     let text = &mut text.sections[0].value;
 
@@ -139,12 +136,14 @@ fn update_ui(mut text: Query<&mut Text>, is_interpolating: Res<IsInterpolating>)
 
     text.push_str("Move box with <WASD>");
     text.push_str("\nToggle interpolation with <Space>");
+    text.push_str("\nChange interpolation mode with <M>");
 
-    if is_interpolating.0 {
+    if settings.enabled {
         text.push_str("\n\nInterpolation: on");
     } else {
         text.push_str("\n\nInterpolation: off");
     }
+    text.push_str(&format!("\nMode: {:?}", settings.default_mode));
 
     text.push_str(&format!(
         "\nPhysics update frequency: {}hz (See PHYSICS_UPDATE_FREQ const in 'examples/box.rs')",
@@ -0,0 +1,99 @@
+//! Checks that the `Transform` of an entity rendering a body at rest is not written, so change detection stays quiet.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{Quaternion, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so most frames blend between two steps.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+
+/// Whether the `Transform` of the rendered entity changed in the last frame.
+#[derive(Resource, Default)]
+struct TransformChanged(bool);
+
+fn record_transform_changed(
+    transform_q: Query<Ref<Transform>, With<InterpolatedTransform>>,
+    mut changed: ResMut<TransformChanged>,
+) {
+    changed.0 = transform_q.single().is_changed();
+}
+
+/// Spawns `body` and an entity rendering it, and runs the first frames, which write the pose of the body.
+/// Runs a few physics steps, as bevy_xpbd wakes up bodies for a couple of steps after spawning them.
+fn setup(body: impl Bundle) -> (App, Entity) {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ))
+        .insert_resource(Gravity::ZERO)
+        .init_resource::<TransformChanged>()
+        .add_systems(Last, record_transform_changed);
+
+    let body = app
+        .world
+        .spawn((
+            body,
+            Position(Vector::new(1.0, 2.0, 3.0)),
+            Rotation(Quaternion::from_rotation_y(0.5)),
+        ))
+        .id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedTransform::from_source(body),
+        ))
+        .id();
+
+    app.update();
+    assert!(app.world.resource::<TransformChanged>().0);
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(
+        app.world.get::<Transform>(render).unwrap().translation,
+        Vec3::new(1.0, 2.0, 3.0)
+    );
+    (app, body)
+}
+
+/// Checks that the `Transform` of the rendered entity stays unchanged for several frames.
+fn assert_transform_unchanged(app: &mut App) {
+    for frame in 0..20 {
+        app.update();
+        assert!(
+            !app.world.resource::<TransformChanged>().0,
+            "the transform changed in frame {frame}"
+        );
+    }
+}
+
+#[test]
+fn static_body_keeps_transform_unchanged() {
+    let (mut app, _) = setup(RigidBody::Static);
+    assert_transform_unchanged(&mut app);
+}
+
+#[test]
+fn sleeping_body_keeps_transform_unchanged() {
+    // Put the body to sleep once it is no longer woken up for being spawned.
+    let (mut app, body) = setup(RigidBody::Dynamic);
+    app.world.entity_mut(body).insert(Sleeping);
+    assert_transform_unchanged(&mut app);
+    assert!(
+        app.world.get::<Sleeping>(body).is_some(),
+        "the body woke up"
+    );
+}
@@ -0,0 +1,37 @@
+//! App setup shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{app::Plugins, prelude::*, scene::ScenePlugin, time::TimeUpdateStrategy};
+use bevy_xpbd_3d::prelude::*;
+
+/// Returns an app with `plugins` and everything they need to run headless, advancing by `frame_time` every update.
+pub fn app<M>(frame_time: Duration, plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ScenePlugin,
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .add_plugins(plugins)
+    .init_asset::<Mesh>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+    app
+}
+
+/// Returns a physics clock stepping `hz` times per second, that runs as many steps per frame as the frame time allows.
+pub fn physics_time(hz: f64) -> Time<Physics> {
+    // By default only a sixtieth of a second is accumulated per frame.
+    let mut physics_time = Time::new_with(Physics::fixed_hz(hz));
+    if let TimestepMode::Fixed {
+        max_delta_overstep, ..
+    } = physics_time.timestep_mode_mut()
+    {
+        *max_delta_overstep = Duration::from_secs(1);
+    }
+    physics_time
+}
@@ -0,0 +1,168 @@
+//! Checks that interpolation follows the `InterpolationOrigin` and `PhysicsOriginShifted`.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, Scalar, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so five frames per step.
+// The body moves 1 unit per second, so 0.125 units per step.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+const STEP_DISTANCE: Scalar = 0.125;
+const EPSILON: Scalar = 1e-3;
+
+fn setup(start: Vector) -> (App, Entity, Entity) {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ));
+    spawn_body(app, start)
+}
+
+/// Like `setup`, but physics steps once per run of `FixedPostUpdate`, so not in every frame.
+fn setup_fixed(start: Vector) -> (App, Entity, Entity) {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::new(FixedPostUpdate),
+            XPBDInterpolationPlugin::new(FixedPostUpdate),
+        ),
+    );
+    app.insert_resource(Time::<Fixed>::from_hz(PHYSICS_HZ))
+        .insert_resource(Time::new_with(Physics::fixed_once_hz(PHYSICS_HZ)));
+    spawn_body(app, start)
+}
+
+/// Spawns a body moving along x from `start` and an entity rendering it, and runs until a physics step has just happened.
+fn spawn_body(mut app: App, start: Vector) -> (App, Entity, Entity) {
+    let body = app
+        .world
+        .spawn((
+            RigidBody::Kinematic,
+            Position(start),
+            LinearVelocity(Vector::X),
+        ))
+        .id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition::from_source(body),
+        ))
+        .id();
+
+    // Run until a step has just happened, so the next frames run no steps.
+    let mut position = start.x;
+    for _ in 0..20 {
+        app.update();
+        let current = app.world.get::<Position>(body).unwrap().x;
+        if current != position
+            && app
+                .world
+                .get::<InterpolatedPosition>(render)
+                .unwrap()
+                .previous_position
+                .is_some()
+        {
+            return (app, body, render);
+        }
+        position = current;
+    }
+    panic!("the body did not move from {start}");
+}
+
+fn rendered_position(app: &App, render: Entity) -> Vector {
+    app.world
+        .get::<Transform>(render)
+        .unwrap()
+        .translation
+        .adjust_precision()
+}
+
+/// Starts the body at `start` on the x axis with the `InterpolationOrigin` at the same point,
+/// and checks that it is rendered between the previous and current physics position, minus the origin.
+fn assert_blended_relative_to_origin(start: Scalar) {
+    let origin = Vector::new(start, 0.0, 0.0);
+    let (mut app, body, render) = setup(origin);
+    app.insert_resource(InterpolationOrigin(origin));
+    app.update();
+
+    let position = app.world.get::<Position>(body).unwrap().x - start;
+    let previous = app
+        .world
+        .get::<InterpolatedPosition>(render)
+        .unwrap()
+        .previous_position
+        .unwrap()
+        .x
+        - start;
+    assert!((position - previous - STEP_DISTANCE).abs() < EPSILON);
+    let rendered = rendered_position(&app, render).x;
+    assert!(
+        rendered > previous + EPSILON && rendered < position - EPSILON,
+        "rendered {rendered} between {previous} and {position}"
+    );
+
+    // The physics state is not affected by the origin.
+    assert!(app.world.get::<Position>(body).unwrap().x > start);
+}
+
+#[test]
+fn render_relative_to_origin() {
+    assert_blended_relative_to_origin(1000.0);
+}
+
+#[cfg(feature = "f64")]
+#[test]
+fn render_relative_to_origin_at_large_coordinates() {
+    // Too far out for single precision to resolve a physics step, double precision still blends between steps.
+    assert_blended_relative_to_origin(1e9);
+}
+
+/// Re-centers the world before every frame, both in frames with and without a physics step,
+/// and checks that the rendered position moves with the world without jumping.
+fn assert_follows_shifts(mut app: App, body: Entity, render: Entity) {
+    app.update();
+    let translation = Vector::new(-50.0, 0.0, 0.0);
+    for frame in 0..10 {
+        let rendered = rendered_position(&app, render).x;
+        app.world.get_mut::<Position>(body).unwrap().0 += translation;
+        app.world.send_event(PhysicsOriginShifted { translation });
+        app.update();
+
+        // The rendered position moves with the world, and only advances by the time of one frame.
+        let shifted = rendered_position(&app, render).x;
+        let advance = shifted - (rendered + translation.x);
+        assert!(
+            (0.0..=STEP_DISTANCE).contains(&advance),
+            "rendered {} after the shift in frame {}, {} before",
+            shifted,
+            frame,
+            rendered
+        );
+    }
+}
+
+#[test]
+fn shift_physics_origin() {
+    let (app, body, render) = setup(Vector::ZERO);
+    assert_follows_shifts(app, body, render);
+}
+
+#[test]
+fn shift_physics_origin_in_fixed_schedule() {
+    // Five frames per physics step, so most shifts happen in frames without a step.
+    let (app, body, render) = setup_fixed(Vector::ZERO);
+    assert_follows_shifts(app, body, render);
+}
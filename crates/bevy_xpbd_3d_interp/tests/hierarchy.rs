@@ -0,0 +1,93 @@
+//! Checks that an interpolated entity nested under a moving interpolated parent is rendered at its interpolated world pose.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{math::Vector, prelude::*};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so the parent also moves in frames without a step.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn follow_interpolated_parent() {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ));
+
+    let parent_body = app
+        .world
+        .spawn((
+            RigidBody::Kinematic,
+            LinearVelocity(Vector::X),
+            AngularVelocity(Vector::Z),
+        ))
+        .id();
+    let child_body = app
+        .world
+        .spawn((
+            RigidBody::Kinematic,
+            Position(Vector::new(0.0, 2.0, 0.0)),
+            LinearVelocity(Vector::Y),
+            AngularVelocity(Vector::X),
+        ))
+        .id();
+
+    let parent = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedTransform::from_source(parent_body),
+        ))
+        .id();
+    let child = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedTransform::from_source(child_body),
+        ))
+        .set_parent(parent)
+        .id();
+    // The same body rendered without a parent, as a reference for the world pose.
+    let reference = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedTransform::from_source(child_body),
+        ))
+        .id();
+
+    for frame in 0..20 {
+        app.update();
+
+        let expected = *app.world.get::<Transform>(reference).unwrap();
+        let (_, rotation, translation) = app
+            .world
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .to_scale_rotation_translation();
+        assert!(
+            translation.abs_diff_eq(expected.translation, EPSILON),
+            "translation {} in frame {}, expected {}",
+            translation,
+            frame,
+            expected.translation
+        );
+        assert!(
+            rotation.abs_diff_eq(expected.rotation, EPSILON),
+            "rotation {} in frame {}, expected {}",
+            rotation,
+            frame,
+            expected.rotation
+        );
+    }
+}
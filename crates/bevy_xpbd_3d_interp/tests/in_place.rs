@@ -0,0 +1,77 @@
+//! Checks that interpolating a rigidbody in place never affects the simulation.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{math::Vector, prelude::*};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Not a multiple of the physics step, so the rendered pose lies between physics states in most frames.
+const FRAME_TIME: Duration = Duration::from_millis(7);
+const FRAMES: usize = 100;
+
+/// Simulates a falling and spinning body with the bundle returned by `interpolation` inserted on it,
+/// and returns its physics pose and rendered translation after every frame.
+fn simulate<B: Bundle>(interpolation: impl FnOnce(Entity) -> B) -> Vec<(Position, Rotation, Vec3)> {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    let body = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            RigidBody::Dynamic,
+            Collider::sphere(0.5),
+            LinearVelocity(Vector::X),
+            AngularVelocity(Vector::new(1.0, 2.0, 3.0)),
+        ))
+        .id();
+    app.world.entity_mut(body).insert(interpolation(body));
+
+    (0..FRAMES)
+        .map(|_| {
+            app.update();
+            let body = app.world.entity(body);
+            (
+                *body.get::<Position>().unwrap(),
+                *body.get::<Rotation>().unwrap(),
+                body.get::<Transform>().unwrap().translation,
+            )
+        })
+        .collect()
+}
+
+fn assert_same_simulation(interpolated: Vec<(Position, Rotation, Vec3)>) {
+    let raw = simulate(|_| ());
+    for (frame, (interpolated, raw)) in interpolated.iter().zip(&raw).enumerate() {
+        // The physics pose is bit-identical to the one simulated without interpolation.
+        assert_eq!(interpolated.0, raw.0, "position differs in frame {frame}");
+        assert_eq!(interpolated.1, raw.1, "rotation differs in frame {frame}");
+    }
+    // While the rendered pose is not the physics pose.
+    assert!(interpolated
+        .iter()
+        .zip(&raw)
+        .any(|(interpolated, raw)| interpolated.2 != raw.2));
+}
+
+#[test]
+fn position_and_rotation_in_place() {
+    assert_same_simulation(simulate(|body| {
+        (
+            InterpolatedPosition::from_source(body),
+            InterpolatedRotation::from_source(body),
+        )
+    }));
+}
+
+#[test]
+fn transform_in_place() {
+    assert_same_simulation(simulate(InterpolatedTransform::from_source));
+}
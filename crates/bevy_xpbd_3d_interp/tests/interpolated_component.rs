@@ -0,0 +1,89 @@
+//! Checks that components registered with `register_interpolated` are interpolated like positions and rotations.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{prelude::*, PhysicsSchedule, PhysicsStepSet};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so five frames per step.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+const EPSILON: f32 = 1e-4;
+
+#[derive(Component)]
+struct Grow;
+
+// Scales the source up by one every physics step.
+fn grow(mut grow_q: Query<&mut Transform, With<Grow>>) {
+    for mut transform in grow_q.iter_mut() {
+        transform.scale += Vec3::ONE;
+    }
+}
+
+#[test]
+fn interpolate_transform_scale() {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ))
+        .register_interpolated::<Transform, Transform>()
+        .add_systems(
+            PhysicsSchedule,
+            grow.after(PhysicsStepSet::Substeps)
+                .before(PhysicsStepSet::SpatialQuery),
+        );
+
+    let source = app.world.spawn((TransformBundle::default(), Grow)).id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedComponent::<Transform>::from_source(source),
+        ))
+        .id();
+
+    let mut rendered = 0.0;
+    for _ in 0..20 {
+        app.update();
+        let current = app.world.get::<Transform>(source).unwrap().scale.x;
+        let Some(previous) = &app
+            .world
+            .get::<InterpolatedComponent<Transform>>(render)
+            .unwrap()
+            .previous
+        else {
+            continue;
+        };
+        // The cached value is the one before the last step.
+        assert_eq!(current - previous.scale.x, 1.0);
+
+        let TimestepMode::Fixed {
+            delta, overstep, ..
+        } = app.world.resource::<Time<Physics>>().timestep_mode()
+        else {
+            unreachable!()
+        };
+        let fraction = overstep.as_secs_f32() / delta.as_secs_f32();
+        let expected = previous.scale.x + fraction;
+        let scale = app.world.get::<Transform>(render).unwrap().scale;
+        assert!(
+            (scale.x - expected).abs() < EPSILON,
+            "rendered {} but expected {}",
+            scale.x,
+            expected
+        );
+        assert_eq!(scale.x, scale.z);
+
+        // The rendered scale grows steadily between physics steps.
+        assert!(scale.x > rendered);
+        rendered = scale.x;
+    }
+    assert!(rendered > 2.0);
+}
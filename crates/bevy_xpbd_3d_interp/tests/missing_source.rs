@@ -0,0 +1,74 @@
+//! Checks that the `MissingSourcePolicy` is applied once when the source of an interpolated entity is despawned.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{ecs::event::ManualEventReader, prelude::*};
+use bevy_xpbd_3d::{math::Vector, prelude::*};
+use bevy_xpbd_3d_interp::prelude::*;
+
+#[test]
+fn apply_policy_once() {
+    let mut app = common::app(
+        Duration::from_millis(10),
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    let body = app
+        .world
+        .spawn((RigidBody::Kinematic, LinearVelocity(Vector::X)))
+        .id();
+    let spawn_render = |world: &mut World, policy| {
+        world
+            .spawn((
+                TransformBundle::default(),
+                InterpolatedPosition::from_source(body).with_missing_source_policy(policy),
+            ))
+            .id()
+    };
+    let freeze = spawn_render(&mut app.world, MissingSourcePolicy::Freeze);
+    let despawn = spawn_render(&mut app.world, MissingSourcePolicy::Despawn);
+    let remove = spawn_render(&mut app.world, MissingSourcePolicy::RemoveComponent);
+    let send_event = spawn_render(&mut app.world, MissingSourcePolicy::SendEvent);
+    let child = app.world.spawn(TransformBundle::default()).id();
+    app.world.entity_mut(despawn).add_child(child);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    let frozen = app.world.get::<Transform>(freeze).unwrap().translation;
+    assert!(frozen.x > 0.0);
+
+    app.world.despawn(body);
+    let mut reader = ManualEventReader::<InterpolationSourceMissing>::default();
+    let mut events = Vec::new();
+    for _ in 0..10 {
+        app.update();
+        let sent = app.world.resource::<Events<InterpolationSourceMissing>>();
+        events.extend(reader.read(sent).copied());
+    }
+
+    // `Freeze` keeps the last rendered pose.
+    assert_eq!(
+        app.world.get::<Transform>(freeze).unwrap().translation,
+        frozen
+    );
+    // `Despawn` despawns the entity along with its children.
+    assert!(app.world.get_entity(despawn).is_none());
+    assert!(app.world.get_entity(child).is_none());
+    // `RemoveComponent` only removes the interpolation component.
+    assert!(app.world.get::<InterpolatedPosition>(remove).is_none());
+    assert!(app.world.get::<Transform>(remove).is_some());
+    // `SendEvent` sends a single event, not one every frame.
+    assert_eq!(
+        events,
+        [InterpolationSourceMissing {
+            entity: send_event,
+            source: body
+        }]
+    );
+    assert!(app.world.get::<InterpolatedPosition>(send_event).is_some());
+}
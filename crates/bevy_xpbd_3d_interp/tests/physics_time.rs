@@ -0,0 +1,148 @@
+//! Checks that interpolation follows pausing and the relative speed of `Time<Physics>`.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, Scalar, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second and a frame takes 25 milliseconds, so five frames per step.
+const PHYSICS_HZ: f64 = 8.0;
+const FRAME_TIME: Duration = Duration::from_millis(25);
+const EPSILON: Scalar = 1e-4;
+
+fn setup(mode: InterpolationMode) -> (App, Entity, Entity) {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    app.insert_resource(common::physics_time(PHYSICS_HZ));
+    spawn_body(app, mode)
+}
+
+/// Like `setup`, but physics steps once per run of `FixedPostUpdate`, so `Time<Fixed>` keeps running while physics is paused.
+fn setup_fixed(mode: InterpolationMode) -> (App, Entity, Entity) {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::new(FixedPostUpdate),
+            XPBDInterpolationPlugin::new(FixedPostUpdate),
+        ),
+    );
+    app.insert_resource(Time::<Fixed>::from_hz(PHYSICS_HZ))
+        .insert_resource(Time::new_with(Physics::fixed_once_hz(PHYSICS_HZ)));
+    spawn_body(app, mode)
+}
+
+/// Spawns a body moving along x at 1 unit per second and an entity rendering it in `mode`, and runs a few frames.
+fn spawn_body(mut app: App, mode: InterpolationMode) -> (App, Entity, Entity) {
+    let body = app
+        .world
+        .spawn((RigidBody::Kinematic, LinearVelocity(Vector::X)))
+        .id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition::from_source(body).with_mode(mode),
+        ))
+        .id();
+    for _ in 0..7 {
+        app.update();
+    }
+    (app, body, render)
+}
+
+fn rendered_position(app: &App, render: Entity) -> Scalar {
+    app.world
+        .get::<Transform>(render)
+        .unwrap()
+        .translation
+        .x
+        .adjust_precision()
+}
+
+/// Pauses `Time<Physics>` and checks that the rendered pose holds still until it is unpaused.
+fn assert_holds_while_paused(mut app: App, body: Entity, render: Entity) {
+    app.world.resource_mut::<Time<Physics>>().pause();
+    // bevy_xpbd still runs the step of the last physics delta in the first physics run after pausing.
+    for _ in 0..5 {
+        app.update();
+    }
+    let position = app.world.get::<Position>(body).unwrap().x;
+    let rendered = rendered_position(&app, render);
+    assert!(rendered > 0.0);
+
+    for frame in 0..20 {
+        app.update();
+        assert_eq!(app.world.get::<Position>(body).unwrap().x, position);
+        assert_eq!(
+            rendered_position(&app, render),
+            rendered,
+            "rendered pose moved in frame {frame}"
+        );
+    }
+
+    // Once unpaused the body is rendered moving again.
+    app.world.resource_mut::<Time<Physics>>().unpause();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(rendered_position(&app, render) > rendered);
+}
+
+#[test]
+fn hold_while_paused() {
+    let (app, body, render) = setup(InterpolationMode::Interpolate);
+    assert_holds_while_paused(app, body, render);
+}
+
+#[test]
+fn hold_while_paused_in_fixed_schedule() {
+    let (app, body, render) = setup_fixed(InterpolationMode::Interpolate);
+    assert_holds_while_paused(app, body, render);
+}
+
+/// Extrapolates with `relative_speed` set on `Time<Physics>` and checks that the body is rendered ahead of its physics position
+/// by the simulated time the accumulated overstep corresponds to.
+fn assert_extrapolates_with_speed(relative_speed: f32) {
+    let (mut app, body, render) = setup(InterpolationMode::Extrapolate { max_time: 1.0 });
+    app.world
+        .resource_mut::<Time<Physics>>()
+        .set_relative_speed(relative_speed);
+
+    let mut extrapolated = false;
+    for frame in 0..20 {
+        app.update();
+        let TimestepMode::Fixed { overstep, .. } =
+            app.world.resource::<Time<Physics>>().timestep_mode()
+        else {
+            unreachable!();
+        };
+        // The body moves 1 unit per simulated second, and the overstep is in real time.
+        let expected =
+            overstep.as_secs_f32().adjust_precision() * relative_speed.adjust_precision();
+        let distance = rendered_position(&app, render) - app.world.get::<Position>(body).unwrap().x;
+        assert!(
+            (distance - expected).abs() < EPSILON,
+            "extrapolated {distance} in frame {frame}, expected {expected}"
+        );
+        extrapolated |= distance > EPSILON;
+    }
+    assert!(extrapolated);
+}
+
+#[test]
+fn extrapolate_at_relative_speed() {
+    assert_extrapolates_with_speed(1.0);
+    assert_extrapolates_with_speed(0.5);
+    assert_extrapolates_with_speed(3.0);
+}
@@ -0,0 +1,57 @@
+//! Checks that `reset_interpolation` clears the cached state of rendered entities.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{ecs::system::CommandQueue, prelude::*};
+use bevy_xpbd_3d::{math::Vector, prelude::*};
+use bevy_xpbd_3d_interp::prelude::*;
+
+#[test]
+fn reset_through_physics_entity() {
+    let mut app = common::app(
+        Duration::from_millis(10),
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    let body = app
+        .world
+        .spawn((
+            RigidBody::Kinematic,
+            LinearVelocity(Vector::X),
+            Interpolated,
+        ))
+        .id();
+    for _ in 0..5 {
+        app.update();
+    }
+    let render = app.world.get::<InterpolationRenderEntity>(body).unwrap().0;
+    assert!(app
+        .world
+        .get::<InterpolatedPosition>(render)
+        .unwrap()
+        .previous_position
+        .is_some());
+
+    // Teleport code usually only knows the physics entity.
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, &app.world)
+        .entity(body)
+        .reset_interpolation();
+    queue.apply(&mut app.world);
+
+    let render = app.world.entity(render);
+    assert!(render
+        .get::<InterpolatedPosition>()
+        .unwrap()
+        .previous_position
+        .is_none());
+    assert!(render
+        .get::<InterpolatedRotation>()
+        .unwrap()
+        .previous_rotation
+        .is_none());
+}
@@ -0,0 +1,120 @@
+//! Checks that interpolation components can be saved in and loaded from a `DynamicScene`.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+    scene::{ron, serde::SceneDeserializer},
+};
+use bevy_xpbd_3d::{math::Vector, prelude::*};
+use bevy_xpbd_3d_interp::prelude::*;
+
+fn setup() -> App {
+    common::app(
+        Duration::from_millis(10),
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    )
+}
+
+#[test]
+fn scene_round_trip() {
+    let mut app = setup();
+    let body = app
+        .world
+        .spawn((RigidBody::Kinematic, LinearVelocity(Vector::X)))
+        .id();
+    let position = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition::from_source(body)
+                .with_mode(InterpolationMode::Hermite)
+                .with_snap_distance(5.0),
+        ))
+        .id();
+    let transform = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedTransform::from_source(body).with_snap_angle(1.0),
+        ))
+        .id();
+    let snapshot = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            SnapshotInterpolated::default().with_max_gap(0.5),
+        ))
+        .id();
+    // Run a few frames so the previous physics state is cached.
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let scene = DynamicSceneBuilder::from_world(&app.world)
+        .deny_all()
+        .allow::<InterpolatedPosition>()
+        .allow::<InterpolatedTransform>()
+        .allow::<SnapshotInterpolated>()
+        .extract_entities([body, position, transform, snapshot].into_iter())
+        .build();
+    let registry = app.world.resource::<AppTypeRegistry>().clone();
+    let serialized = scene.serialize_ron(&registry).unwrap();
+
+    // Load the scene into another world, where the entities get different ids.
+    let mut loaded_app = setup();
+    loaded_app
+        .world
+        .spawn_batch((0..10).map(|_| TransformBundle::default()));
+    let loaded_registry = loaded_app.world.resource::<AppTypeRegistry>().clone();
+    let deserializer = SceneDeserializer {
+        type_registry: &loaded_registry.read(),
+    };
+    let loaded: DynamicScene = ron::Options::default()
+        .from_str_seed(&serialized, deserializer)
+        .unwrap();
+    let mut entity_map = EntityHashMap::default();
+    loaded
+        .write_to_world(&mut loaded_app.world, &mut entity_map)
+        .unwrap();
+
+    // The fields are kept, and the source is remapped to the loaded body.
+    let loaded_body = entity_map[&body];
+    assert_ne!(loaded_body, body);
+    let original = app.world.get::<InterpolatedPosition>(position).unwrap();
+    let interp = loaded_app
+        .world
+        .get::<InterpolatedPosition>(entity_map[&position])
+        .unwrap();
+    assert_eq!(interp.source, loaded_body);
+    assert_eq!(interp.mode, Some(InterpolationMode::Hermite));
+    assert_eq!(interp.snap_distance, Some(5.0));
+    assert!(original.previous_position.is_some());
+    assert_eq!(interp.previous_position, original.previous_position);
+
+    let interp = loaded_app
+        .world
+        .get::<InterpolatedTransform>(entity_map[&transform])
+        .unwrap();
+    assert_eq!(interp.source, loaded_body);
+    assert_eq!(interp.snap_angle, Some(1.0));
+    assert_eq!(
+        interp.previous_rotation,
+        app.world
+            .get::<InterpolatedTransform>(transform)
+            .unwrap()
+            .previous_rotation
+    );
+
+    let interp = loaded_app
+        .world
+        .get::<SnapshotInterpolated>(entity_map[&snapshot])
+        .unwrap();
+    assert_eq!(interp.max_gap, Some(0.5));
+}
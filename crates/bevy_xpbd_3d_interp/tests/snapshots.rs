@@ -0,0 +1,152 @@
+//! Checks that `SnapshotInterpolated` smooths out snapshots that arrive at irregular times.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn setup(frame_time: Duration, interp: SnapshotInterpolated) -> (App, Entity) {
+    let mut app = common::app(
+        frame_time,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    // Let the clocks settle before the entity starts rendering.
+    app.update();
+
+    let entity = app.world.spawn((TransformBundle::default(), interp)).id();
+    (app, entity)
+}
+
+// The sender moves along x at 1 unit per second.
+fn snapshot(time: f64) -> PoseSnapshot {
+    PoseSnapshot::new(
+        time,
+        Vector::new(time.adjust_precision(), 0.0, 0.0),
+        Rotation::default(),
+    )
+}
+
+fn push(app: &mut App, entity: Entity, snapshot: PoseSnapshot) {
+    app.world
+        .get_mut::<SnapshotInterpolated>(entity)
+        .unwrap()
+        .push(snapshot);
+}
+
+fn rendered(app: &App, entity: Entity) -> f32 {
+    app.world.get::<Transform>(entity).unwrap().translation.x
+}
+
+#[test]
+fn interpolate_and_extrapolate() {
+    let (mut app, entity) = setup(
+        Duration::from_millis(25),
+        SnapshotInterpolated::default()
+            .with_render_delay(0.1)
+            .with_max_extrapolation(0.05),
+    );
+    push(&mut app, entity, snapshot(0.0));
+    push(&mut app, entity, snapshot(0.1));
+
+    // Rendering starts 0.1 seconds behind the newest snapshot, interpolates up to it,
+    // and extrapolates for at most 0.05 seconds before holding still.
+    for expected in [0.0, 0.025, 0.05, 0.075, 0.1, 0.125, 0.15, 0.15] {
+        app.update();
+        assert!(
+            (rendered(&app, entity) - expected).abs() < EPSILON,
+            "rendered {} but expected {}",
+            rendered(&app, entity),
+            expected
+        );
+    }
+}
+
+#[test]
+fn out_of_order_snapshots() {
+    let mut interp = SnapshotInterpolated::default().with_capacity(3);
+    for time in [0.2, 0.0, 0.1, 0.3, 0.1] {
+        interp.push(snapshot(time));
+    }
+    // Sorted by time, without duplicates, and only the newest three are kept.
+    let times: Vec<f64> = interp.snapshots().map(|snapshot| snapshot.time).collect();
+    assert_eq!(times, [0.1, 0.2, 0.3]);
+}
+
+#[test]
+fn smooth_despite_jitter() {
+    let (mut app, entity) = setup(
+        Duration::from_millis(10),
+        SnapshotInterpolated::default().with_render_delay(0.1),
+    );
+
+    // Snapshots are sent every 50 milliseconds and arrive up to 40 milliseconds late.
+    let jitter = [0.0, 0.03, 0.01, 0.04, 0.02];
+    let mut next = 0;
+    let mut previous = None;
+    for frame in 0..200 {
+        let now = frame as f64 * 0.01;
+        while next as f64 * 0.05 + jitter[next % jitter.len()] <= now {
+            push(&mut app, entity, snapshot(next as f64 * 0.05));
+            next += 1;
+        }
+        app.update();
+
+        let x = rendered(&app, entity);
+        // After the first snapshots the rendered position advances steadily by about the frame time.
+        if frame > 50 {
+            let advance = x - previous.unwrap();
+            assert!(
+                (0.005..=0.015).contains(&advance),
+                "advanced {advance} in frame {frame}"
+            );
+            // And stays behind the sender by about the render delay.
+            let lag = now as f32 - x;
+            assert!(
+                (0.08..=0.16).contains(&lag),
+                "lagged {lag} in frame {frame}"
+            );
+        }
+        previous = Some(x);
+    }
+}
+
+#[test]
+fn recover_from_gap() {
+    let (mut app, entity) = setup(
+        Duration::from_millis(10),
+        SnapshotInterpolated::default()
+            .with_render_delay(0.1)
+            .with_max_extrapolation(0.05),
+    );
+
+    // Snapshots every 50 milliseconds, with none between 0.5 and 1.5 seconds.
+    for frame in 0..250 {
+        let now = frame as f64 * 0.01;
+        if frame % 5 == 0 && !(0.5..1.5).contains(&now) {
+            push(&mut app, entity, snapshot(now));
+        }
+        app.update();
+
+        let x = rendered(&app, entity);
+        if (0.5..1.5).contains(&now) {
+            // Extrapolate past the newest snapshot for at most `max_extrapolation` seconds.
+            assert!(x <= 0.45 + 0.05 + EPSILON, "rendered {x} at {now}");
+        }
+        if now >= 1.5 {
+            // The render time jumps to the delay behind the first snapshot after the gap.
+            let lag = now as f32 - x;
+            assert!((0.09..=0.15).contains(&lag), "lagged {lag} at {now}");
+        }
+    }
+}
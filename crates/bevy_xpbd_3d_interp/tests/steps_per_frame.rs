@@ -0,0 +1,194 @@
+//! Checks that the previous physics state is cached once per physics step,
+//! no matter how many physics steps run in a frame.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, Scalar, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Physics steps 8 times per second, and the body moves 1 unit per second, so 0.125 units per step.
+// Both are exact in binary, so whole steps accumulate without rounding errors.
+const PHYSICS_HZ: f64 = 8.0;
+const STEP_DISTANCE: Scalar = 0.125;
+const EPSILON: Scalar = 1e-4;
+
+struct TestApp {
+    app: App,
+    body: Entity,
+    render: Entity,
+}
+
+impl TestApp {
+    fn new(plugin: XPBDInterpolationPlugin, frame_time: Duration) -> Self {
+        let mut app = common::app(frame_time, (PhysicsPlugins::default(), plugin));
+        app.insert_resource(common::physics_time(PHYSICS_HZ));
+
+        let body = app
+            .world
+            .spawn((RigidBody::Kinematic, LinearVelocity(Vector::X)))
+            .id();
+        let render = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                InterpolatedPosition::from_source(body),
+            ))
+            .id();
+
+        // Let the clocks settle, the first frame always runs a single step.
+        for _ in 0..3 {
+            app.update();
+        }
+
+        Self { app, body, render }
+    }
+
+    /// Runs a frame and returns the number of physics steps it ran.
+    fn update(&mut self) -> u32 {
+        let before = self.position();
+        self.app.update();
+        ((self.position() - before) / STEP_DISTANCE).round() as u32
+    }
+
+    fn position(&self) -> Scalar {
+        self.app.world.get::<Position>(self.body).unwrap().x
+    }
+
+    fn previous_position(&self) -> Option<Scalar> {
+        self.app
+            .world
+            .get::<InterpolatedPosition>(self.render)
+            .unwrap()
+            .previous_position
+            .map(|previous| previous.x)
+    }
+
+    fn rendered_position(&self) -> Scalar {
+        self.app
+            .world
+            .get::<Transform>(self.render)
+            .unwrap()
+            .translation
+            .x
+            .adjust_precision()
+    }
+
+    /// How far the accumulated time is towards the next physics step, between 0 and 1.
+    fn overstep_fraction(&self) -> Scalar {
+        let TimestepMode::Fixed {
+            delta, overstep, ..
+        } = self.app.world.resource::<Time<Physics>>().timestep_mode()
+        else {
+            unreachable!()
+        };
+        (overstep.as_secs_f64() / delta.as_secs_f64()).adjust_precision()
+    }
+
+    fn assert_interpolated(&self) {
+        let previous = self.previous_position().unwrap();
+        let expected = previous + (self.position() - previous) * self.overstep_fraction();
+        assert!(
+            (self.rendered_position() - expected).abs() < EPSILON,
+            "rendered {} but expected {}",
+            self.rendered_position(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn zero_steps_per_frame() {
+    // Five frames per physics step.
+    let mut test = TestApp::new(
+        XPBDInterpolationPlugin::default(),
+        Duration::from_millis(25),
+    );
+    // Run until a step has just happened, so the next frames run no steps.
+    while test.update() == 0 {}
+
+    let previous = test.previous_position().unwrap();
+    let mut rendered = test.rendered_position();
+    for _ in 0..4 {
+        assert_eq!(test.update(), 0);
+        // Without a physics step the cache must not change, while the rendered position keeps moving towards the current one.
+        assert_eq!(test.previous_position(), Some(previous));
+        assert!(test.rendered_position() > rendered);
+        rendered = test.rendered_position();
+        test.assert_interpolated();
+    }
+}
+
+#[test]
+fn one_step_per_frame() {
+    let mut test = TestApp::new(
+        XPBDInterpolationPlugin::default(),
+        Duration::from_millis(125),
+    );
+    for _ in 0..5 {
+        assert_eq!(test.update(), 1);
+        let previous = test.previous_position().unwrap();
+        assert!((test.position() - previous - STEP_DISTANCE).abs() < EPSILON);
+        test.assert_interpolated();
+    }
+}
+
+#[test]
+fn several_steps_per_frame() {
+    // Three physics steps per frame.
+    let mut test = TestApp::new(
+        XPBDInterpolationPlugin::default(),
+        Duration::from_millis(375),
+    );
+    for _ in 0..5 {
+        assert_eq!(test.update(), 3);
+        // The cache holds the state before the last step, not before the first step of the frame.
+        let previous = test.previous_position().unwrap();
+        assert!((test.position() - previous - STEP_DISTANCE).abs() < EPSILON);
+        test.assert_interpolated();
+    }
+}
+
+#[test]
+fn copy_schedule_outside_physics_step() {
+    // Caching once per frame before physics runs, two physics steps per frame.
+    let mut test = TestApp::new(
+        XPBDInterpolationPlugin::default().with_copy_schedule(PreUpdate),
+        Duration::from_millis(250),
+    );
+    for _ in 0..5 {
+        assert_eq!(test.update(), 2);
+        // The cache is two steps old, so the current physics pose is rendered instead of interpolating across both steps.
+        let previous = test.previous_position().unwrap();
+        assert!((test.position() - previous - 2.0 * STEP_DISTANCE).abs() < EPSILON);
+        assert!((test.rendered_position() - test.position()).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn copy_schedule_outside_physics_step_without_steps() {
+    // Caching once per frame before physics runs, five frames per physics step.
+    let mut test = TestApp::new(
+        XPBDInterpolationPlugin::default().with_copy_schedule(PreUpdate),
+        Duration::from_millis(25),
+    );
+    while test.update() == 0 {}
+    // The first frame after a step caches the new state, later frames without steps must not overwrite it.
+    assert_eq!(test.update(), 0);
+    let previous = test.previous_position().unwrap();
+    assert!((test.position() - previous).abs() < EPSILON);
+    for _ in 0..3 {
+        assert_eq!(test.update(), 0);
+        assert_eq!(test.previous_position(), Some(previous));
+    }
+    // After the next step the cache is one step old, so it is interpolated again.
+    while test.update() == 0 {}
+    let previous = test.previous_position().unwrap();
+    assert!((test.position() - previous - STEP_DISTANCE).abs() < EPSILON);
+    test.assert_interpolated();
+}
@@ -0,0 +1,97 @@
+//! Checks how interpolation follows `TimestepMode::FixedOnce` depending on the schedule physics runs in.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, Scalar, Vector},
+    prelude::*,
+};
+use bevy_xpbd_3d_interp::prelude::*;
+
+// Not a multiple of the physics step, so `Time<Fixed>` accumulates an overstep.
+const FRAME_TIME: Duration = Duration::from_millis(10);
+const EPSILON: Scalar = 1e-4;
+
+fn setup(app: &mut App) -> (Entity, Entity) {
+    app.insert_resource(Time::new_with(Physics::fixed_once_hz(64.0)));
+    let body = app
+        .world
+        .spawn((RigidBody::Kinematic, LinearVelocity(Vector::X)))
+        .id();
+    let render = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition::from_source(body),
+        ))
+        .id();
+    (body, render)
+}
+
+fn rendered_position(app: &App, render: Entity) -> Scalar {
+    app.world
+        .get::<Transform>(render)
+        .unwrap()
+        .translation
+        .x
+        .adjust_precision()
+}
+
+#[test]
+fn fixed_once_in_fixed_schedule() {
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::new(FixedPostUpdate),
+            XPBDInterpolationPlugin::new(FixedPostUpdate),
+        ),
+    );
+    let (body, render) = setup(&mut app);
+
+    for _ in 0..20 {
+        app.update();
+        let Some(previous) = app
+            .world
+            .get::<InterpolatedPosition>(render)
+            .unwrap()
+            .previous_position
+        else {
+            continue;
+        };
+        // Blended by how far `Time<Fixed>` is towards its next step.
+        let fixed_time = app.world.resource::<Time<Fixed>>();
+        let fraction = (fixed_time.overstep().as_secs_f64() / fixed_time.timestep().as_secs_f64())
+            .adjust_precision();
+        let position = app.world.get::<Position>(body).unwrap().0;
+        let expected = previous.lerp(position, fraction).x;
+        assert!(
+            (rendered_position(&app, render) - expected).abs() < EPSILON,
+            "rendered {} but expected {}",
+            rendered_position(&app, render),
+            expected
+        );
+    }
+}
+
+#[test]
+fn fixed_once_every_frame() {
+    // Physics steps once every frame in `PostUpdate`, so there is nothing to interpolate between.
+    let mut app = common::app(
+        FRAME_TIME,
+        (
+            PhysicsPlugins::default(),
+            XPBDInterpolationPlugin::default(),
+        ),
+    );
+    let (body, render) = setup(&mut app);
+
+    for _ in 0..20 {
+        app.update();
+        let position = app.world.get::<Position>(body).unwrap().x;
+        assert!(position > 0.0);
+        assert!((rendered_position(&app, render) - position).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,107 @@
+//! Extension traits with the dimension in their method names.
+//! The extension traits of `dim2` and `dim3` have the same method names, so calling their methods is ambiguous when both are in scope.
+//! These traits forward to them, and are the ones exported by the prelude.
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+/// Defines the extension traits of one dimension, forwarding to the traits of the crate `$dim`.
+macro_rules! dimension_ext {
+    (
+        $dim:ident,
+        $spawn_ext:ident { $spawn_interpolated:ident },
+        $commands_ext:ident { $reset_interpolation:ident, $interpolate_in_place:ident },
+        $app_ext:ident { $register_interpolated:ident }
+    ) => {
+        /// Extension trait for spawning interpolated rigidbodies with `Commands`, see `SpawnInterpolatedExt`.
+        pub trait $spawn_ext {
+            /// See `SpawnInterpolatedExt::spawn_interpolated`.
+            fn $spawn_interpolated(
+                &mut self,
+                physics_bundle: impl Bundle,
+                render_bundle: impl Bundle,
+            ) -> EntityCommands<'_>;
+        }
+
+        impl $spawn_ext for Commands<'_, '_> {
+            fn $spawn_interpolated(
+                &mut self,
+                physics_bundle: impl Bundle,
+                render_bundle: impl Bundle,
+            ) -> EntityCommands<'_> {
+                crate::$dim::commands::SpawnInterpolatedExt::spawn_interpolated(
+                    self,
+                    physics_bundle,
+                    render_bundle,
+                )
+            }
+        }
+
+        /// Extension trait adding interpolation related commands to `EntityCommands`, see `InterpolationCommandsExt`.
+        pub trait $commands_ext {
+            /// See `InterpolationCommandsExt::reset_interpolation`.
+            fn $reset_interpolation(&mut self) -> &mut Self;
+
+            /// See `InterpolationCommandsExt::interpolate_in_place`.
+            fn $interpolate_in_place(&mut self) -> &mut Self;
+        }
+
+        impl $commands_ext for EntityCommands<'_> {
+            fn $reset_interpolation(&mut self) -> &mut Self {
+                crate::$dim::commands::InterpolationCommandsExt::reset_interpolation(self)
+            }
+
+            fn $interpolate_in_place(&mut self) -> &mut Self {
+                crate::$dim::commands::InterpolationCommandsExt::interpolate_in_place(self)
+            }
+        }
+
+        /// Extension trait for registering interpolation of additional components, see `InterpolationAppExt`.
+        pub trait $app_ext {
+            /// See `InterpolationAppExt::register_interpolated`.
+            fn $register_interpolated<S, T>(&mut self) -> &mut Self
+            where
+                S: Component + crate::$dim::interpolate::Interpolate + Clone,
+                T: crate::$dim::interpolate::InterpolationTarget<S>;
+        }
+
+        impl $app_ext for App {
+            fn $register_interpolated<S, T>(&mut self) -> &mut Self
+            where
+                S: Component + crate::$dim::interpolate::Interpolate + Clone,
+                T: crate::$dim::interpolate::InterpolationTarget<S>,
+            {
+                crate::$dim::interpolate::InterpolationAppExt::register_interpolated::<S, T>(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "2d")]
+dimension_ext!(
+    dim2,
+    SpawnInterpolatedExt2d {
+        spawn_interpolated_2d
+    },
+    InterpolationCommandsExt2d {
+        reset_interpolation_2d,
+        interpolate_in_place_2d
+    },
+    InterpolationAppExt2d {
+        register_interpolated_2d
+    }
+);
+
+#[cfg(feature = "3d")]
+dimension_ext!(
+    dim3,
+    SpawnInterpolatedExt3d {
+        spawn_interpolated_3d
+    },
+    InterpolationCommandsExt3d {
+        reset_interpolation_3d,
+        interpolate_in_place_3d
+    },
+    InterpolationAppExt3d {
+        register_interpolated_3d
+    }
+);
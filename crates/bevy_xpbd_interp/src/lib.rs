@@ -0,0 +1,15 @@
+//! **Bevy XPBD Interp** for 2d and 3d from a single crate.
+//! `bevy_xpbd_2d_interp` and `bevy_xpbd_3d_interp` are compiled from the same sources for one dimension each.
+//! This crate depends on both so they can be used in the same build, for example to render a 2d minimap of a 3d world.
+//! Either dimension can be left out by disabling its `2d` or `3d` feature.
+//!
+//! The crates are available as the `dim2` and `dim3` modules, and the prelude exports their types with a `2d` or `3d` suffix.
+//! The methods of the extension traits get the suffix as well, such as `spawn_interpolated_2d` and `reset_interpolation_3d`.
+
+#[cfg(feature = "2d")]
+pub use bevy_xpbd_2d_interp as dim2;
+#[cfg(feature = "3d")]
+pub use bevy_xpbd_3d_interp as dim3;
+
+pub mod ext;
+pub mod prelude;
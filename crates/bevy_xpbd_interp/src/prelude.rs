@@ -0,0 +1,104 @@
+#[cfg(all(feature = "2d", feature = "debug"))]
+pub use crate::dim2::debug::InterpolationDebugConfig as InterpolationDebugConfig2d;
+#[cfg(all(feature = "2d", feature = "debug"))]
+pub use crate::dim2::debug::InterpolationDebugPlugin as InterpolationDebugPlugin2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::diagnostics::InterpolationDiagnosticsPlugin as InterpolationDiagnosticsPlugin2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::interpolate::Interpolate as Interpolate2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::interpolate::InterpolatedComponent as InterpolatedComponent2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::interpolate::InterpolationTarget as InterpolationTarget2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::plugin::XPBDInterpolationPlugin as XPBDInterpolationPlugin2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::snapshot::PoseSnapshot as PoseSnapshot2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::snapshot::SnapshotInterpolated as SnapshotInterpolated2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::Interpolated as Interpolated2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolatedPosition as InterpolatedPosition2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolatedRotation as InterpolatedRotation2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolatedTransform as InterpolatedTransform2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationBatching as InterpolationBatching2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationCopySet as InterpolationCopySet2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationMode as InterpolationMode2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationOrigin as InterpolationOrigin2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationRenderEntity as InterpolationRenderEntity2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationSet as InterpolationSet2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationSettings as InterpolationSettings2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::InterpolationSourceMissing as InterpolationSourceMissing2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::MissingSourcePolicy as MissingSourcePolicy2d;
+#[cfg(feature = "2d")]
+pub use crate::dim2::PhysicsOriginShifted as PhysicsOriginShifted2d;
+#[cfg(all(feature = "3d", feature = "debug"))]
+pub use crate::dim3::debug::InterpolationDebugConfig as InterpolationDebugConfig3d;
+#[cfg(all(feature = "3d", feature = "debug"))]
+pub use crate::dim3::debug::InterpolationDebugPlugin as InterpolationDebugPlugin3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::diagnostics::InterpolationDiagnosticsPlugin as InterpolationDiagnosticsPlugin3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::interpolate::Interpolate as Interpolate3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::interpolate::InterpolatedComponent as InterpolatedComponent3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::interpolate::InterpolationTarget as InterpolationTarget3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::plugin::XPBDInterpolationPlugin as XPBDInterpolationPlugin3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::snapshot::PoseSnapshot as PoseSnapshot3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::snapshot::SnapshotInterpolated as SnapshotInterpolated3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::Interpolated as Interpolated3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolatedPosition as InterpolatedPosition3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolatedRotation as InterpolatedRotation3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolatedTransform as InterpolatedTransform3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationBatching as InterpolationBatching3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationCopySet as InterpolationCopySet3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationMode as InterpolationMode3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationOrigin as InterpolationOrigin3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationRenderEntity as InterpolationRenderEntity3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationSet as InterpolationSet3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationSettings as InterpolationSettings3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::InterpolationSourceMissing as InterpolationSourceMissing3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::MissingSourcePolicy as MissingSourcePolicy3d;
+#[cfg(feature = "3d")]
+pub use crate::dim3::PhysicsOriginShifted as PhysicsOriginShifted3d;
+#[cfg(feature = "2d")]
+pub use crate::ext::InterpolationAppExt2d;
+#[cfg(feature = "3d")]
+pub use crate::ext::InterpolationAppExt3d;
+#[cfg(feature = "2d")]
+pub use crate::ext::InterpolationCommandsExt2d;
+#[cfg(feature = "3d")]
+pub use crate::ext::InterpolationCommandsExt3d;
+#[cfg(feature = "2d")]
+pub use crate::ext::SpawnInterpolatedExt2d;
+#[cfg(feature = "3d")]
+pub use crate::ext::SpawnInterpolatedExt3d;
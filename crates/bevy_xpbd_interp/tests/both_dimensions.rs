@@ -0,0 +1,217 @@
+//! Checks that 2d and 3d interpolation run side by side in one app.
+
+#![cfg(all(feature = "2d", feature = "3d"))]
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    diagnostic::{DiagnosticPath, DiagnosticsPlugin, DiagnosticsStore},
+    ecs::system::CommandQueue,
+    prelude::*,
+};
+// Both dimensions have the same precision, so either conversion trait works for both.
+use bevy_xpbd_3d::math::AdjustPrecision;
+use bevy_xpbd_interp::prelude::*;
+
+fn setup() -> App {
+    common::app(
+        Duration::from_millis(10),
+        (
+            bevy_xpbd_2d::prelude::PhysicsPlugins::default(),
+            bevy_xpbd_3d::prelude::PhysicsPlugins::default(),
+            XPBDInterpolationPlugin2d::default(),
+            XPBDInterpolationPlugin3d::default(),
+        ),
+    )
+}
+
+#[test]
+fn interpolate_2d_and_3d_in_one_app() {
+    let mut app = setup();
+
+    let body_2d = app
+        .world
+        .spawn((
+            bevy_xpbd_2d::prelude::RigidBody::Kinematic,
+            bevy_xpbd_2d::prelude::LinearVelocity(bevy_xpbd_2d::math::Vector::X),
+        ))
+        .id();
+    let render_2d = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition2d::from_source(body_2d),
+        ))
+        .id();
+    let body_3d = app
+        .world
+        .spawn((
+            bevy_xpbd_3d::prelude::RigidBody::Kinematic,
+            bevy_xpbd_3d::prelude::LinearVelocity(bevy_xpbd_3d::math::Vector::Y),
+        ))
+        .id();
+    let render_3d = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            InterpolatedPosition3d::from_source(body_3d),
+        ))
+        .id();
+
+    for _ in 0..20 {
+        app.update();
+    }
+
+    // Both rendered entities follow their own body, trailing it by less than one physics step.
+    let step = 1.0 / 60.0;
+    let position_2d = app
+        .world
+        .get::<bevy_xpbd_2d::prelude::Position>(body_2d)
+        .unwrap()
+        .0;
+    let translation_2d = app.world.get::<Transform>(render_2d).unwrap().translation;
+    assert!(translation_2d.x > 0.0 && position_2d.x - translation_2d.x.adjust_precision() <= step);
+    assert_eq!(translation_2d.y, 0.0);
+
+    let position_3d = app
+        .world
+        .get::<bevy_xpbd_3d::prelude::Position>(body_3d)
+        .unwrap()
+        .0;
+    let translation_3d = app.world.get::<Transform>(render_3d).unwrap().translation;
+    assert!(translation_3d.y > 0.0 && position_3d.y - translation_3d.y.adjust_precision() <= step);
+    assert_eq!(translation_3d.x, 0.0);
+}
+
+#[test]
+fn extension_methods_of_both_dimensions() {
+    let mut app = setup();
+    // The extension traits of both dimensions are in scope, so their methods need distinct names.
+    app.register_interpolated_2d::<Transform, Transform>()
+        .register_interpolated_3d::<Transform, Transform>();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &app.world);
+    let body_2d = commands
+        .spawn_interpolated_2d(
+            bevy_xpbd_2d::prelude::RigidBody::Kinematic,
+            TransformBundle::default(),
+        )
+        .id();
+    let body_3d = commands
+        .spawn_interpolated_3d(
+            bevy_xpbd_3d::prelude::RigidBody::Kinematic,
+            TransformBundle::default(),
+        )
+        .id();
+    let in_place_2d = commands
+        .spawn((
+            bevy_xpbd_2d::prelude::RigidBody::Kinematic,
+            TransformBundle::default(),
+        ))
+        .interpolate_in_place_2d()
+        .id();
+    let in_place_3d = commands
+        .spawn((
+            bevy_xpbd_3d::prelude::RigidBody::Kinematic,
+            TransformBundle::default(),
+        ))
+        .interpolate_in_place_3d()
+        .id();
+    queue.apply(&mut app.world);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let render_2d = app
+        .world
+        .get::<InterpolationRenderEntity2d>(body_2d)
+        .unwrap()
+        .0;
+    let render_3d = app
+        .world
+        .get::<InterpolationRenderEntity3d>(body_3d)
+        .unwrap()
+        .0;
+    assert!(app
+        .world
+        .get::<InterpolatedPosition2d>(render_2d)
+        .unwrap()
+        .previous_position
+        .is_some());
+    assert!(app
+        .world
+        .get::<InterpolatedPosition3d>(render_3d)
+        .unwrap()
+        .previous_position
+        .is_some());
+    assert!(app
+        .world
+        .get::<InterpolatedPosition2d>(in_place_2d)
+        .is_some());
+    assert!(app
+        .world
+        .get::<InterpolatedPosition3d>(in_place_3d)
+        .is_some());
+
+    let mut commands = Commands::new(&mut queue, &app.world);
+    commands.entity(body_2d).reset_interpolation_2d();
+    commands.entity(body_3d).reset_interpolation_3d();
+    queue.apply(&mut app.world);
+
+    assert!(app
+        .world
+        .get::<InterpolatedPosition2d>(render_2d)
+        .unwrap()
+        .previous_position
+        .is_none());
+    assert!(app
+        .world
+        .get::<InterpolatedPosition3d>(render_3d)
+        .unwrap()
+        .previous_position
+        .is_none());
+}
+
+#[test]
+fn diagnostics_of_both_dimensions() {
+    let mut app = setup();
+    app.add_plugins((
+        DiagnosticsPlugin,
+        InterpolationDiagnosticsPlugin2d,
+        InterpolationDiagnosticsPlugin3d,
+    ));
+    app.world.spawn((
+        TransformBundle::default(),
+        SnapshotInterpolated2d::default(),
+    ));
+    for _ in 0..2 {
+        app.world.spawn((
+            TransformBundle::default(),
+            SnapshotInterpolated3d::default(),
+        ));
+    }
+    app.update();
+
+    // Each dimension measures its own entities under its own paths.
+    let entity_count = |path: &DiagnosticPath| {
+        app.world
+            .resource::<DiagnosticsStore>()
+            .get(path)
+            .and_then(|diagnostic| diagnostic.value())
+    };
+    assert_ne!(
+        InterpolationDiagnosticsPlugin2d::ENTITY_COUNT,
+        InterpolationDiagnosticsPlugin3d::ENTITY_COUNT
+    );
+    assert_eq!(
+        entity_count(&InterpolationDiagnosticsPlugin2d::ENTITY_COUNT),
+        Some(1.0)
+    );
+    assert_eq!(
+        entity_count(&InterpolationDiagnosticsPlugin3d::ENTITY_COUNT),
+        Some(2.0)
+    );
+}
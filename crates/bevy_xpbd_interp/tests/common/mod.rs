@@ -0,0 +1,21 @@
+//! App setup shared by the integration tests.
+
+use std::time::Duration;
+
+use bevy::{app::Plugins, prelude::*, scene::ScenePlugin, time::TimeUpdateStrategy};
+
+/// Returns an app with `plugins` and everything they need to run headless, advancing by `frame_time` every update.
+pub fn app<M>(frame_time: Duration, plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ScenePlugin,
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .add_plugins(plugins)
+    .init_asset::<Mesh>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+    app
+}
@@ -21,7 +21,14 @@ cd "$currdir" || exit
 ### Publish bevy_xpbd_3d_interp
 sed 's#\.\./\.\./src#src#g' crates/bevy_xpbd_3d_interp/Cargo.toml > "$tmp"/Cargo.toml
 cp -r crates/bevy_xpbd_3d_interp/examples "$tmp"/.
+cp -r crates/bevy_xpbd_3d_interp/benches "$tmp"/.
 # cd "$tmp" && cargo publish --dry-run
 cd "$tmp" && cargo publish
 
 rm -rf "$tmp"
+
+### Publish bevy_xpbd_interp, which depends on both crates published above
+cd "$currdir"/crates/bevy_xpbd_interp || exit
+# cargo publish --dry-run
+cargo publish
+cd "$currdir" || exit
@@ -0,0 +1,91 @@
+//! Command extensions for working with interpolated entities.
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::prelude::*;
+
+/// Extension trait for spawning interpolated rigidbodies with `Commands`.
+pub trait SpawnInterpolatedExt {
+    /// Spawns an entity with `physics_bundle` that is affected by physics, and a separate rendered entity with `render_bundle`
+    /// that interpolates its position and rotation. The rendered entity is despawned together with the physics entity.
+    /// Returns the `EntityCommands` of the physics entity, which holds an `InterpolationRenderEntity` pointing to the rendered entity.
+    fn spawn_interpolated(
+        &mut self,
+        physics_bundle: impl Bundle,
+        render_bundle: impl Bundle,
+    ) -> EntityCommands<'_>;
+}
+
+impl SpawnInterpolatedExt for Commands<'_, '_> {
+    fn spawn_interpolated(
+        &mut self,
+        physics_bundle: impl Bundle,
+        render_bundle: impl Bundle,
+    ) -> EntityCommands<'_> {
+        let physics_entity = self.spawn(physics_bundle).id();
+        let render_entity = self
+            .spawn((
+                render_bundle,
+                InterpolatedPosition::from_source(physics_entity)
+                    .with_missing_source_policy(MissingSourcePolicy::Despawn),
+                InterpolatedRotation::from_source(physics_entity)
+                    .with_missing_source_policy(MissingSourcePolicy::Despawn),
+            ))
+            .id();
+
+        let mut physics_commands = self.entity(physics_entity);
+        physics_commands.insert((Interpolated, InterpolationRenderEntity(render_entity)));
+        physics_commands
+    }
+}
+
+/// Extension trait adding interpolation related commands to `EntityCommands`.
+pub trait InterpolationCommandsExt {
+    /// Clears the cached previous state of the `InterpolatedPosition`, `InterpolatedRotation` and `InterpolatedTransform` of the entity,
+    /// so the source state is used directly until the next physics update.
+    /// Use this when teleporting the source entity to avoid interpolating across the jump.
+    /// If the entity has an `InterpolationRenderEntity`, such as rigidbodies spawned with `spawn_interpolated`, its rendered entity is reset as well.
+    fn reset_interpolation(&mut self) -> &mut Self;
+
+    /// Inserts `InterpolatedPosition` and `InterpolatedRotation` using the entity itself as the source.
+    /// This allows interpolating a rigidbody without splitting it into a separate physics and rendered entity.
+    fn interpolate_in_place(&mut self) -> &mut Self;
+}
+
+impl InterpolationCommandsExt for EntityCommands<'_> {
+    fn reset_interpolation(&mut self) -> &mut Self {
+        self.add(|entity: Entity, world: &mut World| {
+            let render_entity = world
+                .get::<InterpolationRenderEntity>(entity)
+                .map(|render_entity| render_entity.0);
+            reset_entity(world, entity);
+            if let Some(render_entity) = render_entity {
+                reset_entity(world, render_entity);
+            }
+        })
+    }
+
+    fn interpolate_in_place(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.insert((
+            InterpolatedPosition::from_source(entity),
+            InterpolatedRotation::from_source(entity),
+        ))
+    }
+}
+
+/// Clears the cached previous state of every interpolation component of `entity`.
+fn reset_entity(world: &mut World, entity: Entity) {
+    let Some(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if let Some(mut interp_position) = entity.get_mut::<InterpolatedPosition>() {
+        interp_position.reset();
+    }
+    if let Some(mut interp_rotation) = entity.get_mut::<InterpolatedRotation>() {
+        interp_rotation.reset();
+    }
+    if let Some(mut interp_transform) = entity.get_mut::<InterpolatedTransform>() {
+        interp_transform.reset();
+    }
+}
@@ -0,0 +1,183 @@
+//! Debug rendering of the poses used for interpolation. Requires the `debug` feature.
+
+use bevy::{prelude::*, transform::TransformSystem};
+#[cfg(feature = "2d")]
+use bevy_xpbd_2d::{
+    math::{AsF32, Vector},
+    prelude::*,
+};
+#[cfg(feature = "3d")]
+use bevy_xpbd_3d::{
+    math::{AsF32, Vector},
+    prelude::*,
+};
+
+use crate::prelude::*;
+
+/// Draws gizmos for the previous physics pose, the current physics pose and the rendered pose of every interpolated entity.
+/// Requires the `GizmoPlugin`, which is part of the `DefaultPlugins`.
+pub struct InterpolationDebugPlugin;
+
+impl Plugin for InterpolationDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InterpolationDebugConfig>()
+            .register_type::<InterpolationDebugConfig>()
+            .register_type::<Option<Color>>()
+            .add_systems(
+                PostUpdate,
+                draw_interpolation_gizmos
+                    .after(TransformSystem::TransformPropagate)
+                    .run_if(|config: Res<InterpolationDebugConfig>| config.enabled),
+            );
+    }
+}
+
+/// Configures what the `InterpolationDebugPlugin` draws.
+/// Each pose is drawn as a set of axes.
+#[derive(Resource, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct InterpolationDebugConfig {
+    /// If disabled nothing is drawn.
+    pub enabled: bool,
+    /// The color of the pose cached by the copy systems. If `None`, the previous pose is not drawn.
+    pub previous_pose_color: Option<Color>,
+    /// The color of the current pose of the source entity. If `None`, the current pose is not drawn.
+    pub current_pose_color: Option<Color>,
+    /// The color of the pose written to the `Transform`. If `None`, the rendered pose is not drawn.
+    pub rendered_pose_color: Option<Color>,
+    /// The length of the axes drawn for each pose.
+    pub axis_length: f32,
+}
+
+impl Default for InterpolationDebugConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            previous_pose_color: Some(Color::RED),
+            current_pose_color: Some(Color::GREEN),
+            rendered_pose_color: Some(Color::YELLOW),
+            axis_length: 0.5,
+        }
+    }
+}
+
+/// Draws the previous, current and rendered pose of every entity with an `InterpolatedPosition`, `InterpolatedRotation` or `InterpolatedTransform`.
+/// Parts of the previous pose that have not been cached yet are drawn at the current pose.
+/// Runs in `PostUpdate` after `TransformSystem::TransformPropagate`.
+#[allow(clippy::type_complexity)]
+fn draw_interpolation_gizmos(
+    mut gizmos: Gizmos,
+    config: Res<InterpolationDebugConfig>,
+    interp_q: Query<
+        (
+            &GlobalTransform,
+            Option<&InterpolatedPosition>,
+            Option<&InterpolatedRotation>,
+            Option<&InterpolatedTransform>,
+        ),
+        Or<(
+            With<InterpolatedPosition>,
+            With<InterpolatedRotation>,
+            With<InterpolatedTransform>,
+        )>,
+    >,
+    source_q: Query<(&Position, &Rotation)>,
+    origin: Res<InterpolationOrigin>,
+) {
+    for (global_transform, interp_position, interp_rotation, interp_transform) in interp_q.iter() {
+        let Some(source) = interp_transform
+            .map(|interp| interp.source)
+            .or(interp_position.map(|interp| interp.source))
+            .or(interp_rotation.map(|interp| interp.source))
+        else {
+            continue;
+        };
+        let Ok((position, rotation)) = source_q.get(source) else {
+            continue;
+        };
+        let current_rotation = crate::rotation_value(rotation);
+
+        if let Some(color) = config.previous_pose_color {
+            let previous_position = interp_transform
+                .and_then(|interp| interp.previous_position)
+                .or(interp_position.and_then(|interp| interp.previous_position))
+                .unwrap_or(position.0);
+            let previous_rotation = interp_transform
+                .and_then(|interp| interp.previous_rotation)
+                .or(interp_rotation.and_then(|interp| interp.previous_rotation))
+                .unwrap_or(current_rotation);
+            draw_pose(
+                &mut gizmos,
+                vector_to_translation(previous_position - origin.0),
+                crate::rotation_value_to_quat(previous_rotation),
+                config.axis_length,
+                color,
+            );
+        }
+
+        if let Some(color) = config.current_pose_color {
+            draw_pose(
+                &mut gizmos,
+                vector_to_translation(position.0 - origin.0),
+                crate::rotation_value_to_quat(current_rotation),
+                config.axis_length,
+                color,
+            );
+        }
+
+        if let Some(color) = config.rendered_pose_color {
+            let (_, rotation, translation) = global_transform.to_scale_rotation_translation();
+            draw_pose(
+                &mut gizmos,
+                translation,
+                rotation,
+                config.axis_length,
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "2d")]
+fn vector_to_translation(vector: Vector) -> Vec3 {
+    vector.f32().extend(0.0)
+}
+
+#[cfg(feature = "3d")]
+fn vector_to_translation(vector: Vector) -> Vec3 {
+    vector.f32()
+}
+
+#[cfg(feature = "2d")]
+fn draw_pose(gizmos: &mut Gizmos, translation: Vec3, rotation: Quat, length: f32, color: Color) {
+    let origin = translation.truncate();
+    gizmos.line_2d(
+        origin,
+        origin + (rotation * Vec3::X).truncate() * length,
+        color,
+    );
+    gizmos.line_2d(
+        origin,
+        origin + (rotation * Vec3::Y).truncate() * length,
+        color,
+    );
+}
+
+#[cfg(feature = "3d")]
+fn draw_pose(gizmos: &mut Gizmos, translation: Vec3, rotation: Quat, length: f32, color: Color) {
+    gizmos.line(
+        translation,
+        translation + rotation * Vec3::X * length,
+        color,
+    );
+    gizmos.line(
+        translation,
+        translation + rotation * Vec3::Y * length,
+        color,
+    );
+    gizmos.line(
+        translation,
+        translation + rotation * Vec3::Z * length,
+        color,
+    );
+}
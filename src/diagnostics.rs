@@ -0,0 +1,164 @@
+//! Diagnostics for the health and cost of interpolation.
+
+use std::time::Duration;
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    ecs::schedule::ScheduleLabel,
+    prelude::*,
+    utils::Instant,
+};
+#[cfg(feature = "2d")]
+use bevy_xpbd_2d::{PhysicsSchedule, PhysicsStepSet};
+#[cfg(feature = "3d")]
+use bevy_xpbd_3d::{PhysicsSchedule, PhysicsStepSet};
+
+use crate::prelude::*;
+
+/// Returns the `DiagnosticPath` named `$name` in the namespace of the dimension,
+/// so the diagnostics of the 2d and 3d crates can be registered in the same app.
+#[cfg(feature = "2d")]
+macro_rules! diagnostic_path {
+    ($name:literal) => {
+        DiagnosticPath::const_new(concat!("interpolation_2d/", $name))
+    };
+}
+#[cfg(feature = "3d")]
+macro_rules! diagnostic_path {
+    ($name:literal) => {
+        DiagnosticPath::const_new(concat!("interpolation_3d/", $name))
+    };
+}
+
+/// Registers diagnostics for interpolation, which can be shown with the `LogDiagnosticsPlugin` for example.
+/// Requires the `XPBDInterpolationPlugin` to be added first.
+pub struct InterpolationDiagnosticsPlugin;
+
+impl InterpolationDiagnosticsPlugin {
+    /// The number of entities with an `InterpolatedPosition`, `InterpolatedRotation`, `InterpolatedTransform` or `SnapshotInterpolated`.
+    /// Entities that only interpolate components registered with `register_interpolated` are not counted.
+    pub const ENTITY_COUNT: DiagnosticPath = diagnostic_path!("entity_count");
+    /// The number of `InterpolatedPosition`, `InterpolatedRotation` and `InterpolatedTransform` components
+    /// skipped during the last interpolation because their source was invalid.
+    pub const INVALID_SOURCES: DiagnosticPath = diagnostic_path!("invalid_sources");
+    /// How far along the way from the previous to the current physics state the last interpolation was, between 0 and 1.
+    /// This is 1 when the physics state is passed through without interpolation.
+    pub const BLEND_FACTOR: DiagnosticPath = diagnostic_path!("blend_factor");
+    /// The time spent in `InterpolationCopySet` since the last interpolation, in milliseconds.
+    pub const COPY_TIME: DiagnosticPath = diagnostic_path!("copy_time");
+    /// The time spent in `InterpolationSet::Interpolation`, in milliseconds.
+    pub const INTERPOLATION_TIME: DiagnosticPath = diagnostic_path!("interpolation_time");
+}
+
+impl Plugin for InterpolationDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let (copy_schedule, schedule) = app
+            .world
+            .get_resource::<crate::InterpolationSchedules>()
+            .map_or(
+                (PhysicsSchedule.intern(), PostUpdate.intern()),
+                |schedules| (schedules.copy, schedules.interpolate),
+            );
+
+        app.init_resource::<SystemTimers>()
+            .register_diagnostic(Diagnostic::new(Self::ENTITY_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::INVALID_SOURCES))
+            .register_diagnostic(Diagnostic::new(Self::BLEND_FACTOR))
+            .register_diagnostic(Diagnostic::new(Self::COPY_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::INTERPOLATION_TIME).with_suffix("ms"));
+
+        // The timers measure the wall time between the start and end of the sets,
+        // which includes any other systems running in parallel with them.
+        app.add_systems(
+            copy_schedule,
+            (
+                start_copy_timer.before(InterpolationCopySet),
+                stop_copy_timer
+                    .after(InterpolationCopySet)
+                    .before(PhysicsStepSet::BroadPhase),
+            ),
+        )
+        .add_systems(
+            schedule,
+            (
+                start_interpolation_timer.before(InterpolationSet::Interpolation),
+                measure_diagnostics
+                    .after(InterpolationSet::Interpolation)
+                    .before(InterpolationSet::PostInterpolation),
+            ),
+        );
+    }
+}
+
+#[derive(Resource, Default)]
+struct SystemTimers {
+    // When the current run of `InterpolationCopySet` started
+    copy_start: Option<Instant>,
+    // The time spent copying since the last interpolation, summed over all physics steps
+    copy_time: Duration,
+    // When the current run of `InterpolationSet::Interpolation` started
+    interpolation_start: Option<Instant>,
+}
+
+fn start_copy_timer(mut timers: ResMut<SystemTimers>) {
+    timers.copy_start = Some(Instant::now());
+}
+
+fn stop_copy_timer(mut timers: ResMut<SystemTimers>) {
+    if let Some(start) = timers.copy_start.take() {
+        timers.copy_time += start.elapsed();
+    }
+}
+
+fn start_interpolation_timer(mut timers: ResMut<SystemTimers>) {
+    timers.interpolation_start = Some(Instant::now());
+}
+
+/// Runs after `InterpolationSet::Interpolation`.
+#[allow(clippy::type_complexity)]
+fn measure_diagnostics(
+    mut diagnostics: Diagnostics,
+    mut timers: ResMut<SystemTimers>,
+    interp_q: Query<
+        (
+            Option<&InterpolatedPosition>,
+            Option<&InterpolatedRotation>,
+            Option<&InterpolatedTransform>,
+        ),
+        Or<(
+            With<InterpolatedPosition>,
+            With<InterpolatedRotation>,
+            With<InterpolatedTransform>,
+            With<SnapshotInterpolated>,
+        )>,
+    >,
+    timing: Res<crate::InterpolationTiming>,
+) {
+    if let Some(start) = timers.interpolation_start.take() {
+        let elapsed = start.elapsed();
+        diagnostics.add_measurement(&InterpolationDiagnosticsPlugin::INTERPOLATION_TIME, || {
+            elapsed.as_secs_f64() * 1000.0
+        });
+    }
+    let copy_time = std::mem::take(&mut timers.copy_time);
+    diagnostics.add_measurement(&InterpolationDiagnosticsPlugin::COPY_TIME, || {
+        copy_time.as_secs_f64() * 1000.0
+    });
+
+    diagnostics.add_measurement(&InterpolationDiagnosticsPlugin::ENTITY_COUNT, || {
+        interp_q.iter().count() as f64
+    });
+    diagnostics.add_measurement(&InterpolationDiagnosticsPlugin::INVALID_SOURCES, || {
+        interp_q
+            .iter()
+            .map(|(position, rotation, transform)| {
+                usize::from(position.is_some_and(|interp| interp.source_missing))
+                    + usize::from(rotation.is_some_and(|interp| interp.source_missing))
+                    + usize::from(transform.is_some_and(|interp| interp.source_missing))
+            })
+            .sum::<usize>() as f64
+    });
+    diagnostics.add_measurement(&InterpolationDiagnosticsPlugin::BLEND_FACTOR, || {
+        timing.0.map_or(1.0, |timing| timing.lerp_factor as f64)
+    });
+}
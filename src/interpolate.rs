@@ -0,0 +1,256 @@
+//! Interpolation of arbitrary components that change at the physics rate.
+
+use bevy::{
+    ecs::schedule::ScheduleLabel,
+    math::{DQuat, DVec2, DVec3, DVec4},
+    prelude::*,
+};
+#[cfg(feature = "2d")]
+use bevy_xpbd_2d::{prelude::*, PhysicsSchedule};
+#[cfg(feature = "3d")]
+use bevy_xpbd_3d::{prelude::*, PhysicsSchedule};
+
+use crate::prelude::*;
+
+/// A value that can be blended between its state at the previous and current physics update.
+pub trait Interpolate {
+    /// Returns the value `t` of the way from `self` to `other`, where `t` is between 0 and 1.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vec4 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Quat {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * f64::from(t)
+    }
+}
+
+impl Interpolate for DVec2 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, f64::from(t))
+    }
+}
+
+impl Interpolate for DVec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, f64::from(t))
+    }
+}
+
+impl Interpolate for DVec4 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, f64::from(t))
+    }
+}
+
+impl Interpolate for DQuat {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, f64::from(t))
+    }
+}
+
+/// Interpolates the translation and scale linearly, and the rotation spherically.
+/// Use it to interpolate a `Transform`, including its scale, that is changed at the physics rate.
+impl Interpolate for Transform {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.interpolate(&other.translation, t),
+            rotation: self.rotation.interpolate(&other.rotation, t),
+            scale: self.scale.interpolate(&other.scale, t),
+        }
+    }
+}
+
+impl Interpolate for LinearVelocity {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self(self.0.interpolate(&other.0, t))
+    }
+}
+
+impl Interpolate for AngularVelocity {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self(self.0.interpolate(&other.0, t))
+    }
+}
+
+/// A component the interpolated value of `S` can be written into.
+/// Every component is a target for its own values, implement this to write values into other components,
+/// for example a scale component into the `Transform`.
+pub trait InterpolationTarget<S>: Component {
+    fn set_interpolated(&mut self, value: S);
+}
+
+impl<S: Component> InterpolationTarget<S> for S {
+    fn set_interpolated(&mut self, value: S) {
+        *self = value;
+    }
+}
+
+/// Caches the value of the `S` component of the source entity from the previous physics update.
+/// The interpolated value is written into the target component registered with `register_interpolated::<S, Target>()`.
+/// The source entity should not have an `InterpolatedComponent<S>` itself.
+#[derive(Component)]
+pub struct InterpolatedComponent<S: Component> {
+    pub source: Entity,
+    // The value of the source component the previous physics update.
+    pub previous: Option<S>,
+    // If enabled the source value will be passed through directly without interpolation.
+    pub pass_raw: bool,
+    // What happens when the source entity is despawned or has no `S` component.
+    pub missing_source_policy: MissingSourcePolicy,
+    // Whether the source was missing the last time interpolation ran.
+    source_missing: bool,
+}
+
+impl<S: Component> InterpolatedComponent<S> {
+    pub fn from_source(source: Entity) -> Self {
+        Self {
+            source,
+            previous: None,
+            pass_raw: false,
+            missing_source_policy: MissingSourcePolicy::default(),
+            source_missing: false,
+        }
+    }
+
+    pub fn with_missing_source_policy(mut self, policy: MissingSourcePolicy) -> Self {
+        self.missing_source_policy = policy;
+        self
+    }
+
+    /// Clears the cached value so that the source value is used directly until the next physics update.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Extension trait for registering interpolation of additional components.
+pub trait InterpolationAppExt {
+    /// Interpolates the `S` component of the source of every `InterpolatedComponent<S>` and writes the result into its `T` component.
+    /// The value is cached in `InterpolationCopySet` and interpolated in `InterpolationSet::Interpolation`, like positions and rotations.
+    /// Requires the `XPBDInterpolationPlugin` to be added first.
+    fn register_interpolated<S, T>(&mut self) -> &mut Self
+    where
+        S: Component + Interpolate + Clone,
+        T: InterpolationTarget<S>;
+}
+
+impl InterpolationAppExt for App {
+    fn register_interpolated<S, T>(&mut self) -> &mut Self
+    where
+        S: Component + Interpolate + Clone,
+        T: InterpolationTarget<S>,
+    {
+        let (copy_schedule, schedule) = self
+            .world
+            .get_resource::<crate::InterpolationSchedules>()
+            .map_or(
+                (PhysicsSchedule.intern(), PostUpdate.intern()),
+                |schedules| (schedules.copy, schedules.interpolate),
+            );
+        self.add_systems(
+            copy_schedule,
+            copy_component::<S>.in_set(InterpolationCopySet),
+        )
+        .add_systems(
+            schedule,
+            interpolate_component::<S, T>
+                .after(crate::update_interpolation_timing)
+                .in_set(InterpolationSet::Interpolation),
+        )
+    }
+}
+
+/// Caches the `S` value of the source entity for every `InterpolatedComponent<S>`.
+/// Runs in `InterpolationCopySet`.
+fn copy_component<S: Component + Clone>(
+    mut interp_q: Query<&mut InterpolatedComponent<S>>,
+    source_q: Query<&S, Without<InterpolatedComponent<S>>>,
+    batching: Res<crate::InterpolationBatching>,
+) {
+    interp_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|mut interp| {
+            let Ok(value) = source_q.get(interp.source) else {
+                // Missing sources are handled when interpolating.
+                return;
+            };
+            interp.previous = Some(value.clone());
+        });
+}
+
+/// Interpolates the `S` value of the source entity for every `InterpolatedComponent<S>` and stores the result in its `T` component.
+/// Runs in `InterpolationSet::Interpolation`.
+fn interpolate_component<S, T>(
+    mut interp_q: Query<(Entity, &mut T, &mut InterpolatedComponent<S>)>,
+    source_q: Query<&S, Without<InterpolatedComponent<S>>>,
+    timing: Res<crate::InterpolationTiming>,
+    par_commands: ParallelCommands,
+    batching: Res<crate::InterpolationBatching>,
+) where
+    S: Component + Interpolate + Clone,
+    T: InterpolationTarget<S>,
+{
+    interp_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|(entity, mut target, mut interp)| {
+            let Ok(current) = source_q.get(interp.source) else {
+                // The source entity must exist and have an `S` component.
+                if !interp.source_missing {
+                    interp.source_missing = true;
+                    par_commands.command_scope(|mut commands| {
+                        crate::handle_missing_source::<InterpolatedComponent<S>>(
+                            &mut commands,
+                            entity,
+                            interp.source,
+                            interp.missing_source_policy,
+                        );
+                    });
+                }
+                return;
+            };
+            if interp.source_missing {
+                interp.source_missing = false;
+            }
+
+            let value = match (timing.0, &interp.previous) {
+                (Some(timing), Some(previous)) if !interp.pass_raw => {
+                    previous.interpolate(current, timing.lerp_factor)
+                }
+                // Use the current value directly without interpolating.
+                _ => current.clone(),
+            };
+            target.set_interpolated(value);
+        });
+}
@@ -2,21 +2,56 @@
 //! It operates by interpolating between the position/rotation of the current and previous physics update based on how much time has accumulated since the last physics update.
 //! The interpolated value is then stored in the `Transform` of some separate entity that may hold meshes/cameras etc.
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        event::ManualEventReader,
+        query::BatchingStrategy,
+        reflect::ReflectMapEntities,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::*,
+};
 #[cfg(feature = "2d")]
-use bevy_xpbd_2d::{math::PI, prelude::*};
+use bevy_xpbd_2d::{
+    math::{AdjustPrecision, AsF32, Quaternion, Scalar, Vector, PI},
+    prelude::*,
+};
 #[cfg(feature = "3d")]
-use bevy_xpbd_3d::prelude::*;
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, AsF32, Quaternion, Scalar, Vector},
+    prelude::*,
+};
 
+pub mod commands;
+#[cfg(feature = "debug")]
+pub mod debug;
+pub mod diagnostics;
+pub mod interpolate;
 pub mod plugin;
 pub mod prelude;
+pub mod snapshot;
 
-/// System set running in `PhysicsUpdate` before `PhysicsStepSet::BroadPhase`
-#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash)]
+/// The cached value of a `Rotation`: the angle in radians in 2d, and the quaternion in 3d.
+#[cfg(feature = "2d")]
+type RotationValue = Scalar;
+/// The cached value of a `Rotation`: the angle in radians in 2d, and the quaternion in 3d.
+#[cfg(feature = "3d")]
+type RotationValue = Quaternion;
+
+/// The value of an `AngularVelocity`: a scalar in 2d, and a vector in 3d.
+#[cfg(feature = "2d")]
+type AngularVelocityValue = Scalar;
+/// The value of an `AngularVelocity`: a scalar in 2d, and a vector in 3d.
+#[cfg(feature = "3d")]
+type AngularVelocityValue = Vector;
+
+/// System set running in `PhysicsSchedule` before `PhysicsStepSet::BroadPhase` by default
+#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash, Reflect)]
 pub struct InterpolationCopySet;
 
-/// System set running in `PostUpdate` between `PhysicsSet::Sync` and `TransformSystem::TransformPropagate`
-#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash)]
+/// System set running in `PostUpdate` by default, between `PhysicsSet::Sync` and `TransformSystem::TransformPropagate`
+#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash, Reflect)]
 pub enum InterpolationSet {
     /// Where the interpolation takes place.
     Interpolation,
@@ -25,219 +60,1660 @@ pub enum InterpolationSet {
     PostInterpolation,
 }
 
-/// Does not store the actual interpolated position value, but instead the cached position from the previous physics update and the entity holding the `Position` affected by a `Rigidbody`.
-/// The interpolated position value is automatically given to the `Transform` of any entity with a `InterpolatedPosition`.
-#[cfg(feature = "3d")]
-#[derive(Component)]
-pub struct InterpolatedPosition {
+/// Determines how the rendered value is computed from the state of the physics object.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum InterpolationMode {
+    /// Interpolate between the previous and current physics state.
+    /// The rendered value lags one physics update behind the simulation.
+    #[default]
+    Interpolate,
+    /// Predict forward from the current physics state using the `LinearVelocity`/`AngularVelocity` of the source entity.
+    /// The rendered value is never behind the simulation, but may overshoot when the velocity changes.
+    Extrapolate {
+        /// The maximum amount of seconds to extrapolate past the current physics state.
+        max_time: f32,
+    },
+    /// Interpolate between the previous and current physics state along a cubic Hermite curve,
+    /// using the `LinearVelocity`/`AngularVelocity` at both states as tangents.
+    /// This gives smooth curved motion for fast orbiting or bouncing objects at low physics update frequencies.
+    /// Falls back to `Interpolate` if the source entity has no velocity.
+    Hermite,
+}
+
+/// Global settings for interpolation, initialized from the `XPBDInterpolationPlugin`.
+/// Every interpolated entity follows these settings, unless it sets its own `mode` or opts out of interpolation with `pass_raw`.
+/// Change them at runtime to switch interpolation for the whole world at once, for example from a settings menu.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct InterpolationSettings {
+    /// If disabled the physics state of every entity is rendered without interpolation.
+    pub enabled: bool,
+    /// The mode used by interpolation components that do not set their own.
+    pub default_mode: InterpolationMode,
+}
+
+impl Default for InterpolationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_mode: InterpolationMode::default(),
+        }
+    }
+}
+
+/// How the copy and interpolation systems split interpolated entities into batches to process them in parallel.
+/// Set from the `XPBDInterpolationPlugin`, and can be changed at runtime.
+#[derive(Resource, Clone)]
+pub struct InterpolationBatching(pub BatchingStrategy);
+
+impl Default for InterpolationBatching {
+    fn default() -> Self {
+        Self(BatchingStrategy::new())
+    }
+}
+
+/// Determines what happens to an interpolated entity when its source entity is despawned or lacks the required components.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum MissingSourcePolicy {
+    /// Keep the entity at its last rendered pose and log a warning.
+    #[default]
+    Freeze,
+    /// Despawn the entity along with its children.
+    Despawn,
+    /// Remove the `InterpolatedPosition`/`InterpolatedRotation` component from the entity.
+    RemoveComponent,
+    /// Keep the entity at its last rendered pose and send an `InterpolationSourceMissing` event.
+    SendEvent,
+}
+
+/// Sent when the source of an entity with `MissingSourcePolicy::SendEvent` is despawned or lacks the required components.
+/// Sent once for every time the source goes missing.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterpolationSourceMissing {
+    /// The interpolated entity.
+    pub entity: Entity,
+    /// The source entity that could not be found.
     pub source: Entity,
-    // The position of the source entity the previous physics update.
-    pub previous_position: Option<Vec3>,
-    // If enabled the source position will be passed through directly without interpolation.
-    pub pass_raw: bool,
+}
+
+/// The physics position that is rendered at the origin of the world.
+/// Interpolated translations are written to the `Transform` relative to it, so a large world can be rendered around the camera
+/// in single precision, for example with the `f64` feature. Moving it only changes where things are rendered, not the physics state.
+/// Entities that are their own source get their physics position back without the origin before the next physics update.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct InterpolationOrigin(pub Vector);
+
+/// Send this when the physics world is re-centered by moving every `Position` by `translation`,
+/// so the cached previous positions move along with the world instead of being interpolated across the jump.
+/// It should be sent in the same frame the positions are moved, before physics runs, for example in `Update`.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct PhysicsOriginShifted {
+    /// The translation that was added to every `Position`.
+    pub translation: Vector,
 }
 
 /// Does not store the actual interpolated position value, but instead the cached position from the previous physics update and the entity holding the `Position` affected by a `Rigidbody`.
 /// The interpolated position value is automatically given to the `Transform` of any entity with a `InterpolatedPosition`.
-#[cfg(feature = "2d")]
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct InterpolatedPosition {
     pub source: Entity,
     // The position of the source entity the previous physics update.
-    pub previous_position: Option<Vec2>,
+    pub previous_position: Option<Vector>,
+    // The linear velocity of the source entity the previous physics update. Used by `InterpolationMode::Hermite`.
+    pub previous_linear_velocity: Option<Vector>,
+    // Translation of the rendered entity relative to the source, in the local space of the source.
+    // Rotates along with the interpolated rotation if the entity also has an `InterpolatedRotation`.
+    pub offset: Vector,
+    // If the source moved further than this distance during the previous physics update it is considered teleported,
+    // and the source position is passed through directly instead of interpolating across the jump.
+    pub snap_distance: Option<Scalar>,
     // If enabled the source position will be passed through directly without interpolation.
     pub pass_raw: bool,
+    // How the rendered position is computed from the source position, or `None` to use the default mode of the `InterpolationSettings`.
+    pub mode: Option<InterpolationMode>,
+    // What happens when the source entity is despawned or has no `Position`.
+    pub missing_source_policy: MissingSourcePolicy,
+    // Whether the source was missing the last time interpolation ran.
+    #[reflect(ignore)]
+    source_missing: bool,
+    // The translation last written to the `Transform` when the source is the entity itself.
+    #[reflect(ignore)]
+    in_place_translation: Option<Vec3>,
+    // The interpolated world space position of an entity with a parent, written by `write_parented_poses` once the parent is interpolated.
+    #[reflect(ignore)]
+    pending_translation: Option<Vector>,
 }
 
 impl InterpolatedPosition {
+    /// The source may be the entity itself, in which case the interpolated position is written to the `Transform` of the rigidbody
+    /// and the physics position is restored before the next physics update.
     pub fn from_source(source: Entity) -> Self {
         Self {
             source,
             previous_position: None,
+            previous_linear_velocity: None,
+            offset: Default::default(),
+            snap_distance: None,
             pass_raw: false,
+            mode: None,
+            missing_source_policy: MissingSourcePolicy::default(),
+            source_missing: false,
+            in_place_translation: None,
+            pending_translation: None,
         }
     }
+
+    pub fn with_mode(mut self, mode: InterpolationMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn with_missing_source_policy(mut self, policy: MissingSourcePolicy) -> Self {
+        self.missing_source_policy = policy;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_snap_distance(mut self, snap_distance: Scalar) -> Self {
+        self.snap_distance = Some(snap_distance);
+        self
+    }
+
+    /// Clears the cached state so that the source position is used directly until the next physics update.
+    /// Call this when teleporting the source entity to avoid interpolating across the jump.
+    pub fn reset(&mut self) {
+        self.previous_position = None;
+        self.previous_linear_velocity = None;
+    }
 }
 
 /// Does not store the actual interpolated rotation value, but instead the cached rotation from the previous physics update and the entity holding the ´Rotation´ affected by a `Rigidbody`.
 /// The interpolated rotation value is automatically given to the `Transform` of any entity with a `InterpolatedRotation`.
-#[cfg(feature = "3d")]
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct InterpolatedRotation {
     pub source: Entity,
-    // The rotation of the source entity the previous physics update.
-    pub previous_rotation: Option<Quat>,
+    // The rotation of the source entity the previous physics update, as an angle in 2d.
+    pub previous_rotation: Option<RotationValue>,
+    // The angular velocity of the source entity the previous physics update. Used by `InterpolationMode::Hermite`.
+    pub previous_angular_velocity: Option<AngularVelocityValue>,
+    // Rotation of the rendered entity relative to the source, in the local space of the source, as an angle in 2d.
+    pub offset: RotationValue,
+    // If the source rotated more than this angle (in radians) during the previous physics update it is considered teleported,
+    // and the source rotation is passed through directly instead of interpolating across the jump.
+    pub snap_angle: Option<Scalar>,
     // If enabled the source rotation will be passed through directly without interpolation.
     pub pass_raw: bool,
+    // How the rendered rotation is computed from the source rotation, or `None` to use the default mode of the `InterpolationSettings`.
+    pub mode: Option<InterpolationMode>,
+    // What happens when the source entity is despawned or has no `Rotation`.
+    pub missing_source_policy: MissingSourcePolicy,
+    // Whether the source was missing the last time interpolation ran.
+    #[reflect(ignore)]
+    source_missing: bool,
+    // The rotation last written to the `Transform` when the source is the entity itself.
+    #[reflect(ignore)]
+    in_place_rotation: Option<Quat>,
+    // The interpolated world space rotation of an entity with a parent, written by `write_parented_poses` once the parent is interpolated.
+    #[reflect(ignore)]
+    pending_rotation: Option<Quat>,
 }
 
-/// Does not store the actual interpolated rotation value, but instead the cached rotation from the previous physics update and the entity holding the ´Rotation´ affected by a `Rigidbody`.
-/// The interpolated rotation value is automatically given to the `Transform` of any entity with a `InterpolatedRotation`.
-#[cfg(feature = "2d")]
-#[derive(Component)]
-pub struct InterpolatedRotation {
+impl InterpolatedRotation {
+    /// The source may be the entity itself, in which case the interpolated rotation is written to the `Transform` of the rigidbody
+    /// and the physics rotation is restored before the next physics update.
+    pub fn from_source(source: Entity) -> Self {
+        Self {
+            source,
+            previous_rotation: None,
+            previous_angular_velocity: None,
+            offset: Default::default(),
+            snap_angle: None,
+            pass_raw: false,
+            mode: None,
+            missing_source_policy: MissingSourcePolicy::default(),
+            source_missing: false,
+            in_place_rotation: None,
+            pending_rotation: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: InterpolationMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn with_missing_source_policy(mut self, policy: MissingSourcePolicy) -> Self {
+        self.missing_source_policy = policy;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: RotationValue) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the rotation offset as a quaternion.
+    fn offset_quat(&self) -> Quaternion {
+        #[cfg(feature = "2d")]
+        {
+            Quaternion::from_rotation_z(self.offset)
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.offset
+        }
+    }
+
+    pub fn with_snap_angle(mut self, snap_angle: Scalar) -> Self {
+        self.snap_angle = Some(snap_angle);
+        self
+    }
+
+    /// Clears the cached state so that the source rotation is used directly until the next physics update.
+    /// Call this when teleporting the source entity to avoid interpolating across the jump.
+    pub fn reset(&mut self) {
+        self.previous_rotation = None;
+        self.previous_angular_velocity = None;
+    }
+}
+
+/// Combines `InterpolatedPosition` and `InterpolatedRotation` into a single component with one source.
+/// Caches the position and rotation from the previous physics update, and the interpolated values are automatically given to the `Transform` of any entity with a `InterpolatedTransform`.
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct InterpolatedTransform {
     pub source: Entity,
-    // Angle of the rotation of the source entity the previous physics update.
-    pub previous_rotation: Option<f32>,
-    // If enabled the source rotation will be passed through directly without interpolation.
+    // The position of the source entity the previous physics update.
+    pub previous_position: Option<Vector>,
+    // The linear velocity of the source entity the previous physics update. Used by `InterpolationMode::Hermite`.
+    pub previous_linear_velocity: Option<Vector>,
+    // The rotation of the source entity the previous physics update, as an angle in 2d.
+    pub previous_rotation: Option<RotationValue>,
+    // The angular velocity of the source entity the previous physics update. Used by `InterpolationMode::Hermite`.
+    pub previous_angular_velocity: Option<AngularVelocityValue>,
+    // Translation of the rendered entity relative to the source, in the local space of the source.
+    pub position_offset: Vector,
+    // Rotation of the rendered entity relative to the source, in the local space of the source, as an angle in 2d.
+    pub rotation_offset: RotationValue,
+    // If the source moved further than this distance during the previous physics update it is considered teleported.
+    pub snap_distance: Option<Scalar>,
+    // If the source rotated more than this angle (in radians) during the previous physics update it is considered teleported.
+    pub snap_angle: Option<Scalar>,
+    // If enabled the source position and rotation will be passed through directly without interpolation.
     pub pass_raw: bool,
+    // How the rendered position and rotation are computed from the source, or `None` to use the default mode of the `InterpolationSettings`.
+    pub mode: Option<InterpolationMode>,
+    // What happens when the source entity is despawned or has no `Position`/`Rotation`.
+    pub missing_source_policy: MissingSourcePolicy,
+    // Whether the source was missing the last time interpolation ran.
+    #[reflect(ignore)]
+    source_missing: bool,
+    // The translation last written to the `Transform` when the source is the entity itself.
+    #[reflect(ignore)]
+    in_place_translation: Option<Vec3>,
+    // The rotation last written to the `Transform` when the source is the entity itself.
+    #[reflect(ignore)]
+    in_place_rotation: Option<Quat>,
+    // The interpolated world space position of an entity with a parent, written by `write_parented_poses` once the parent is interpolated.
+    #[reflect(ignore)]
+    pending_translation: Option<Vector>,
+    // The interpolated world space rotation of an entity with a parent, written by `write_parented_poses` once the parent is interpolated.
+    #[reflect(ignore)]
+    pending_rotation: Option<Quat>,
 }
 
-impl InterpolatedRotation {
+impl InterpolatedTransform {
+    /// The source may be the entity itself, in which case the interpolated values are written to the `Transform` of the rigidbody
+    /// and the physics pose is restored before the next physics update.
     pub fn from_source(source: Entity) -> Self {
         Self {
             source,
+            previous_position: None,
+            previous_linear_velocity: None,
             previous_rotation: None,
+            previous_angular_velocity: None,
+            position_offset: Default::default(),
+            rotation_offset: Default::default(),
+            snap_distance: None,
+            snap_angle: None,
             pass_raw: false,
+            mode: None,
+            missing_source_policy: MissingSourcePolicy::default(),
+            source_missing: false,
+            in_place_translation: None,
+            in_place_rotation: None,
+            pending_translation: None,
+            pending_rotation: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: InterpolationMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn with_missing_source_policy(mut self, policy: MissingSourcePolicy) -> Self {
+        self.missing_source_policy = policy;
+        self
+    }
+
+    pub fn with_offset(mut self, position_offset: Vector, rotation_offset: RotationValue) -> Self {
+        self.position_offset = position_offset;
+        self.rotation_offset = rotation_offset;
+        self
+    }
+
+    /// Returns the rotation offset as a quaternion.
+    fn rotation_offset_quat(&self) -> Quaternion {
+        #[cfg(feature = "2d")]
+        {
+            Quaternion::from_rotation_z(self.rotation_offset)
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.rotation_offset
+        }
+    }
+
+    pub fn with_snap_distance(mut self, snap_distance: Scalar) -> Self {
+        self.snap_distance = Some(snap_distance);
+        self
+    }
+
+    pub fn with_snap_angle(mut self, snap_angle: Scalar) -> Self {
+        self.snap_angle = Some(snap_angle);
+        self
+    }
+
+    /// Clears the cached state so that the source position and rotation are used directly until the next physics update.
+    /// Call this when teleporting the source entity to avoid interpolating across the jump.
+    pub fn reset(&mut self) {
+        self.previous_position = None;
+        self.previous_linear_velocity = None;
+        self.previous_rotation = None;
+        self.previous_angular_velocity = None;
+    }
+}
+
+/// Marker component for rigidbodies that should automatically be given a separate rendered entity with `InterpolatedPosition` and `InterpolatedRotation`.
+/// The rendered entity is despawned together with the rigidbody, or when this component is removed.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Interpolated;
+
+/// Points to the rendered entity interpolating the rigidbody holding this component.
+/// Inserted for rigidbodies with `Interpolated`, or spawned with `spawn_interpolated`.
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct InterpolationRenderEntity(pub Entity);
+
+impl MapEntities for InterpolationRenderEntity {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+impl MapEntities for InterpolatedPosition {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.source = entity_mapper.map_entity(self.source);
+    }
+}
+
+impl MapEntities for InterpolatedRotation {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.source = entity_mapper.map_entity(self.source);
+    }
+}
+
+impl MapEntities for InterpolatedTransform {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.source = entity_mapper.map_entity(self.source);
+    }
+}
+
+/// Spawns a rendered entity for every rigidbody with `Interpolated` that doesn't have one yet.
+/// Runs in `PostUpdate` before `PhysicsSet::Prepare`.
+#[allow(clippy::type_complexity)]
+fn spawn_render_entities(
+    mut commands: Commands,
+    body_q: Query<
+        (Entity, Option<&Transform>),
+        (
+            With<RigidBody>,
+            With<Interpolated>,
+            Without<InterpolationRenderEntity>,
+        ),
+    >,
+) {
+    for (entity, transform) in body_q.iter() {
+        let render_entity = commands
+            .spawn((
+                SpatialBundle::from_transform(transform.copied().unwrap_or_default()),
+                InterpolatedPosition::from_source(entity)
+                    .with_missing_source_policy(MissingSourcePolicy::Despawn),
+                InterpolatedRotation::from_source(entity)
+                    .with_missing_source_policy(MissingSourcePolicy::Despawn),
+            ))
+            .id();
+        commands
+            .entity(entity)
+            .try_insert(InterpolationRenderEntity(render_entity));
+    }
+}
+
+/// Despawns the rendered entity of every rigidbody that had its `Interpolated` component removed.
+/// Rendered entities of despawned rigidbodies are handled by `MissingSourcePolicy::Despawn`.
+/// Runs in `PostUpdate` before `PhysicsSet::Prepare`.
+fn despawn_render_entities(
+    mut commands: Commands,
+    mut removed: RemovedComponents<Interpolated>,
+    body_q: Query<&InterpolationRenderEntity>,
+) {
+    for entity in removed.read() {
+        if let Ok(render_entity) = body_q.get(entity) {
+            commands.entity(render_entity.0).despawn_recursive();
+            commands
+                .entity(entity)
+                .remove::<InterpolationRenderEntity>();
         }
     }
 }
 
-/// Caches the `Position` value of the source entity for every `InterpolatedPosition`.
+/// Counts the physics steps and the step the physics state was last cached at.
+/// The cache is only valid for interpolation if exactly one step has completed since it was taken.
+#[derive(Resource, Default)]
+pub(crate) struct PhysicsSteps {
+    // The number of completed physics steps
+    completed: u64,
+    // The number of completed physics steps when `InterpolationCopySet` last ran
+    copied: Option<u64>,
+}
+
+impl PhysicsSteps {
+    /// The number of physics steps completed since the physics state was last cached.
+    fn since_copy(&self) -> u64 {
+        self.copied
+            .map_or(0, |copied| self.completed.saturating_sub(copied))
+    }
+}
+
+/// Run condition of `InterpolationCopySet`, so the physics state is cached at most once per physics step,
+/// even if the copy schedule runs several times per step or while no steps are run.
+fn physics_step_pending(steps: Res<PhysicsSteps>) -> bool {
+    steps.copied != Some(steps.completed)
+}
+
+/// Records the step the physics state was cached at.
+/// Runs after `InterpolationCopySet`.
+fn mark_step_copied(mut steps: ResMut<PhysicsSteps>) {
+    steps.copied = Some(steps.completed);
+}
+
+/// Counts completed physics steps.
+/// Runs in `PhysicsSchedule` after `PhysicsStepSet::SpatialQuery`.
+fn complete_physics_step(mut steps: ResMut<PhysicsSteps>) {
+    steps.completed += 1;
+}
+
+/// Caches the `Position` and `LinearVelocity` values of the source entity for every `InterpolatedPosition`.
 /// Runs in `InterpolationCopySet`.
 fn copy_position(
     mut interp_position_q: Query<&mut InterpolatedPosition>,
-    source_position_q: Query<&Position>,
+    source_position_q: Query<(&Position, Option<&LinearVelocity>)>,
+    batching: Res<InterpolationBatching>,
 ) {
-    for mut interp in interp_position_q.iter_mut() {
-        let position = source_position_q.get(interp.source).unwrap();
-        interp.previous_position = Some(position.0);
-    }
+    interp_position_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|mut interp| {
+            let Ok((position, linear_velocity)) = source_position_q.get(interp.source) else {
+                // Missing sources are handled when interpolating.
+                return;
+            };
+            interp.previous_position = Some(position.0);
+            interp.previous_linear_velocity = linear_velocity.map(|v| v.0);
+        });
 }
 
-/// Caches the `Rotation` value of the source entity for every `InterpolatedRotation`.
+/// Caches the `Rotation` and `AngularVelocity` values of the source entity for every `InterpolatedRotation`.
 /// Runs in `InterpolationCopySet`.
 fn copy_rotation(
     mut interp_rotation_q: Query<&mut InterpolatedRotation>,
-    source_rotation_q: Query<&Rotation>,
+    source_rotation_q: Query<(&Rotation, Option<&AngularVelocity>)>,
+    batching: Res<InterpolationBatching>,
 ) {
+    interp_rotation_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|mut interp| {
+            let Ok((rotation, angular_velocity)) = source_rotation_q.get(interp.source) else {
+                // Missing sources are handled when interpolating.
+                return;
+            };
+            interp.previous_rotation = Some(rotation_value(rotation));
+            interp.previous_angular_velocity = angular_velocity.map(|v| v.0);
+        });
+}
+
+/// Caches the `Position`, `Rotation`, `LinearVelocity` and `AngularVelocity` values of the source entity for every `InterpolatedTransform`.
+/// Runs in `InterpolationCopySet`.
+fn copy_transform(
+    mut interp_transform_q: Query<&mut InterpolatedTransform>,
+    source_q: Query<(
+        &Position,
+        &Rotation,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+    )>,
+    batching: Res<InterpolationBatching>,
+) {
+    interp_transform_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|mut interp| {
+            let Ok((position, rotation, linear_velocity, angular_velocity)) =
+                source_q.get(interp.source)
+            else {
+                // Missing sources are handled when interpolating.
+                return;
+            };
+            interp.previous_position = Some(position.0);
+            interp.previous_linear_velocity = linear_velocity.map(|v| v.0);
+            interp.previous_rotation = Some(rotation_value(rotation));
+            interp.previous_angular_velocity = angular_velocity.map(|v| v.0);
+        });
+}
+
+/// Applies the `MissingSourcePolicy` of an interpolated entity whose source has just gone missing.
+/// `T` is the interpolation component that was unable to find its source.
+fn handle_missing_source<T: Component>(
+    commands: &mut Commands,
+    entity: Entity,
+    source: Entity,
+    policy: MissingSourcePolicy,
+) {
+    match policy {
+        MissingSourcePolicy::Freeze => {
+            warn!(
+                "Invalid source entity {:?} for {} on {:?}. Freezing at the last rendered value.",
+                source,
+                std::any::type_name::<T>(),
+                entity
+            );
+        }
+        MissingSourcePolicy::Despawn => {
+            commands.entity(entity).despawn_recursive();
+        }
+        MissingSourcePolicy::RemoveComponent => {
+            commands.entity(entity).remove::<T>();
+        }
+        MissingSourcePolicy::SendEvent => {
+            commands.add(move |world: &mut World| {
+                world.send_event(InterpolationSourceMissing { entity, source });
+            });
+        }
+    }
+}
+
+/// Returns the value of a `Rotation` in the form it is cached in: the angle in 2d, and the quaternion in 3d.
+fn rotation_value(rotation: &Rotation) -> RotationValue {
+    #[cfg(feature = "2d")]
+    {
+        rotation.as_radians()
+    }
+    #[cfg(feature = "3d")]
+    {
+        rotation.0
+    }
+}
+
+/// Converts a cached rotation value into a quaternion in the precision of the physics engine.
+fn rotation_value_to_quaternion(rotation: RotationValue) -> Quaternion {
     #[cfg(feature = "2d")]
-    for mut interp in interp_rotation_q.iter_mut() {
-        let rotation = source_rotation_q.get(interp.source).unwrap();
-        interp.previous_rotation = Some(rotation.as_radians());
+    {
+        Quaternion::from(Rotation::from_radians(rotation))
+    }
+    #[cfg(feature = "3d")]
+    {
+        rotation
     }
+}
+
+/// Converts a cached rotation value into a quaternion that can be used in a `Transform`.
+fn rotation_value_to_quat(rotation: RotationValue) -> Quat {
+    rotation_value_to_quaternion(rotation).f32()
+}
 
+/// Returns the angle between two cached rotation values.
+fn angle_between(from: RotationValue, to: RotationValue) -> Scalar {
+    #[cfg(feature = "2d")]
+    {
+        shortest_angle_delta(from, to).abs()
+    }
     #[cfg(feature = "3d")]
-    for mut interp in interp_rotation_q.iter_mut() {
-        let rotation = source_rotation_q.get(interp.source).unwrap();
-        interp.previous_rotation = Some(rotation.0);
+    {
+        from.angle_between(to)
     }
 }
 
-/// Performs position interpolation and stores the result in the `Transform` of the entity with the `InterpolatedPosition`.
-/// Runs in `InterpolationSet::Interpolation`.
-fn interpolate_position(
-    mut interp_q: Query<(&mut Transform, &InterpolatedPosition)>,
-    source_q: Query<&Position>,
+/// Rotates an offset given in the local space of the source into world space.
+fn rotate_offset(rotation: Quaternion, offset: Vector) -> Vector {
+    #[cfg(feature = "2d")]
+    {
+        (rotation * offset.extend(0.0)).truncate()
+    }
+    #[cfg(feature = "3d")]
+    {
+        rotation * offset
+    }
+}
+
+/// Converts a point from world space into the local space of `parent`, or returns it as is if there is no parent.
+fn world_to_local_point(parent: Option<&GlobalTransform>, point: Vec3) -> Vec3 {
+    parent.map_or(point, |parent| {
+        parent.affine().inverse().transform_point3(point)
+    })
+}
+
+/// Converts a rotation from world space into the local space of `parent`, or returns it as is if there is no parent.
+fn world_to_local_rotation(parent: Option<&GlobalTransform>, rotation: Quat) -> Quat {
+    parent.map_or(rotation, |parent| {
+        parent.to_scale_rotation_translation().1.inverse() * rotation
+    })
+}
+
+/// Stores a world space position in the translation of `transform`, converting it into the local space of `parent`.
+/// The position is only reduced to single precision here, after blending.
+/// In 2d the z coordinate of the `Transform` is kept.
+fn set_world_translation(
+    transform: &mut Transform,
+    parent: Option<&GlobalTransform>,
+    position: Vector,
+) {
+    #[cfg(feature = "2d")]
+    let translation = {
+        let z = parent.map_or(transform.translation.z, |parent| {
+            parent.transform_point(transform.translation).z
+        });
+        position.f32().extend(z)
+    };
+    #[cfg(feature = "3d")]
+    let translation = position.f32();
+    transform.translation = world_to_local_point(parent, translation);
+}
+
+/// Returns the cubic Hermite basis functions `[h00, h10, h01, h11]` evaluated at `t`.
+fn hermite_basis(t: Scalar) -> [Scalar; 4] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        2.0 * t3 - 3.0 * t2 + 1.0,
+        t3 - 2.0 * t2 + t,
+        -2.0 * t3 + 3.0 * t2,
+        t3 - t2,
+    ]
+}
+
+/// Returns the signed difference `to - from` between two angles, taking the shortest way around the circle.
+#[cfg(feature = "2d")]
+fn shortest_angle_delta(from: Scalar, to: Scalar) -> Scalar {
+    let delta = (to - from) % (2.0 * PI);
+    if delta > PI {
+        delta - 2.0 * PI
+    } else if delta < -PI {
+        delta + 2.0 * PI
+    } else {
+        delta
+    }
+}
+
+/// Where the rendered frame lies in time relative to the previous and current physics state.
+#[derive(Clone, Copy, Debug)]
+struct BlendTiming {
+    // The simulated time between two physics states in seconds, used to scale velocities into tangents
+    delta: f32,
+    // How far along the way from the previous to the current physics state to interpolate, between 0 and 1
+    lerp_factor: f32,
+    // How far past the current physics state to extrapolate in simulated seconds
+    extrapolation_time: f32,
+}
+
+impl BlendTiming {
+    /// Returns `(delta, lerp_factor, extrapolation_time)` in the precision of the physics engine.
+    fn adjusted(self) -> (Scalar, Scalar, Scalar) {
+        (
+            self.delta.adjust_precision(),
+            self.lerp_factor.adjust_precision(),
+            self.extrapolation_time.adjust_precision(),
+        )
+    }
+}
+
+/// The `BlendTiming` of the current frame, shared by all interpolation systems.
+/// `None` if physics runs in sync with rendering, in which case the physics pose is used directly.
+#[derive(Resource, Default)]
+pub(crate) struct InterpolationTiming(Option<BlendTiming>);
+
+/// The schedules the `XPBDInterpolationPlugin` was configured with, used to add systems for additional interpolated components.
+#[derive(Resource)]
+pub(crate) struct InterpolationSchedules {
+    pub physics: InternedScheduleLabel,
+    pub copy: InternedScheduleLabel,
+    pub interpolate: InternedScheduleLabel,
+}
+
+impl InterpolationSchedules {
+    /// Whether physics runs in one of the schedules of `FixedMain`, in which case `Time<Fixed>` tells when it steps.
+    fn physics_in_fixed_main(&self) -> bool {
+        [
+            FixedFirst.intern(),
+            FixedPreUpdate.intern(),
+            FixedUpdate.intern(),
+            FixedPostUpdate.intern(),
+            FixedLast.intern(),
+        ]
+        .contains(&self.physics)
+    }
+}
+
+/// Computes the `InterpolationTiming` of the current frame from the `TimestepMode` of `Time<Physics>`.
+/// With `FixedOnce` physics steps once per run of its schedule. If that is one of the schedules of `FixedMain`, such as `FixedUpdate`,
+/// the overstep of `Time<Fixed>` is used. Otherwise physics steps once per frame, so the physics pose is passed through without interpolation.
+/// With `Variable` physics steps by the frame time, so the physics pose is passed through without interpolation.
+/// While physics is paused the timing of the last frame is kept, so the rendered pose holds still.
+/// If interpolation is disabled in the `InterpolationSettings` the physics pose is passed through as well.
+/// If more than one physics step completed since the physics state was cached, the cache is stale and the physics pose is passed through too.
+/// Runs in `InterpolationSet::Interpolation` before the interpolation systems.
+fn update_interpolation_timing(
+    mut timing: ResMut<InterpolationTiming>,
     phys_time: Res<Time<Physics>>,
+    fixed_time: Res<Time<Fixed>>,
+    settings: Res<InterpolationSettings>,
+    steps: Res<PhysicsSteps>,
+    schedules: Res<InterpolationSchedules>,
+    mut warned: Local<bool>,
 ) {
-    // Get the physics time-step
-    let (delta, overstep) = match phys_time.timestep_mode() {
+    // A stale cache happens when `InterpolationCopySet` runs outside of the physics step, for example once per frame.
+    if !settings.enabled || steps.since_copy() > 1 {
+        timing.0 = None;
+        return;
+    }
+
+    // The time between physics steps, the time accumulated since the last step and the simulated length of a step.
+    // Relative speed only scales how far each step advances the simulation, not how often steps run.
+    let relative_speed = phys_time.relative_speed();
+    let (period, overstep, step) = match phys_time.timestep_mode() {
         TimestepMode::Fixed {
             delta, overstep, ..
-        } => (delta.as_secs_f32(), overstep.as_secs_f32()),
-        _ => {
-            warn!("The 'PhysicsTimestep' resource does not hold the 'Fixed' variant. Cannot interpolate.");
+        } => (delta, overstep, delta.mul_f32(relative_speed)),
+        TimestepMode::FixedOnce { delta } if schedules.physics_in_fixed_main() => (
+            fixed_time.timestep(),
+            fixed_time.overstep(),
+            delta.mul_f32(relative_speed),
+        ),
+        TimestepMode::FixedOnce { .. } => {
+            if !*warned {
+                *warned = true;
+                warn!("Physics runs once per frame outside of a fixed schedule, so the physics pose is rendered without interpolation.");
+            }
+            timing.0 = None;
+            return;
+        }
+        TimestepMode::Variable { .. } => {
+            if !*warned {
+                *warned = true;
+                warn!("Physics runs with a variable timestep, so the physics pose is rendered without interpolation.");
+            }
+            timing.0 = None;
             return;
         }
     };
 
-    for (mut transform, interp_position) in interp_q.iter_mut() {
-        let current_position = match source_q.get(interp_position.source) {
-            Ok(val) => val,
-            Err(_) => {
-                warn!("Invalid source entity for InterpolatedPosition. The source entity must have a position component.");
-                continue;
-            }
-        };
+    // The accumulated time is not meaningful while paused, so keep blending the same way as before the pause.
+    if phys_time.is_paused() && timing.0.is_some() {
+        return;
+    }
 
-        #[cfg(feature = "2d")]
-        if interp_position.pass_raw || interp_position.previous_position == None {
-            // Use the current position of the physics object directly without interpolating.
-            transform.translation = Vec3::new(current_position.0.x, current_position.0.y, 0.0);
-        } else {
+    let fraction = overstep.as_secs_f32() / period.as_secs_f32();
+    timing.0 = Some(BlendTiming {
+        delta: step.as_secs_f32(),
+        lerp_factor: fraction.clamp(0.0, 1.0),
+        extrapolation_time: fraction * step.as_secs_f32(),
+    });
+}
+
+/// Computes the rendered position in world space from the cached and current state of the source according to `mode`.
+/// `previous_position` is expected to already be checked against the snap distance.
+fn blend_position(
+    mode: InterpolationMode,
+    previous_position: Option<Vector>,
+    previous_velocity: Option<Vector>,
+    current_position: Vector,
+    current_velocity: Option<Vector>,
+    timing: BlendTiming,
+) -> Vector {
+    let (delta, lerp_factor, extrapolation_time) = timing.adjusted();
+    match mode {
+        InterpolationMode::Interpolate => {
             // Interpolate between the previous and current position of the physics object.
-            let lerp_factor = overstep / delta;
-            if let Some(previous_position) = interp_position.previous_position {
-                let interp = previous_position.lerp(current_position.0, lerp_factor);
-                transform.translation = Vec3::new(interp.x, interp.y, 0.0);
+            previous_position.map_or(current_position, |previous_position| {
+                previous_position.lerp(current_position, lerp_factor)
+            })
+        }
+        InterpolationMode::Hermite => {
+            match (previous_position, previous_velocity, current_velocity) {
+                (Some(previous_position), Some(previous_velocity), Some(current_velocity)) => {
+                    // Follow the curve through both positions with the velocities scaled to the time-step as tangents.
+                    let [h00, h10, h01, h11] = hermite_basis(lerp_factor);
+                    h00 * previous_position
+                        + h10 * delta * previous_velocity
+                        + h01 * current_position
+                        + h11 * delta * current_velocity
+                }
+                (Some(previous_position), ..) => {
+                    previous_position.lerp(current_position, lerp_factor)
+                }
+                (None, ..) => current_position,
+            }
+        }
+        InterpolationMode::Extrapolate { max_time } => {
+            // Move the current position of the physics object forward along its velocity.
+            current_position
+                + current_velocity.unwrap_or(Vector::ZERO)
+                    * extrapolation_time.min(max_time.adjust_precision())
+        }
+    }
+}
+
+/// Computes the rendered rotation in world space from the cached and current state of the source according to `mode`.
+/// `previous_rotation` is expected to already be checked against the snap angle.
+#[cfg(feature = "2d")]
+fn blend_rotation(
+    mode: InterpolationMode,
+    previous_rotation: Option<RotationValue>,
+    previous_velocity: Option<AngularVelocityValue>,
+    current_rotation: RotationValue,
+    current_velocity: Option<AngularVelocityValue>,
+    timing: BlendTiming,
+) -> Quaternion {
+    let (delta, lerp_factor, extrapolation_time) = timing.adjusted();
+    let angle = match mode {
+        InterpolationMode::Interpolate => {
+            // Interpolate between the previous and current angle of the physics object.
+            // Angles are kept between -pi and pi by bevy_xpbd, so take the shortest way around when crossing between the second and third quadrant.
+            previous_rotation.map_or(current_rotation, |previous_rotation| {
+                previous_rotation
+                    + lerp_factor * shortest_angle_delta(previous_rotation, current_rotation)
+            })
+        }
+        InterpolationMode::Hermite => match previous_rotation {
+            Some(previous_rotation) => {
+                let delta_angle = shortest_angle_delta(previous_rotation, current_rotation);
+                if let (Some(previous_velocity), Some(current_velocity)) =
+                    (previous_velocity, current_velocity)
+                {
+                    // Hermite curve over the unwrapped angle with the angular velocities scaled to the time-step as tangents.
+                    let [_, h10, h01, h11] = hermite_basis(lerp_factor);
+                    previous_rotation
+                        + h10 * delta * previous_velocity
+                        + h01 * delta_angle
+                        + h11 * delta * current_velocity
+                } else {
+                    previous_rotation + lerp_factor * delta_angle
+                }
             }
+            None => current_rotation,
+        },
+        InterpolationMode::Extrapolate { max_time } => {
+            // Rotate the current rotation of the physics object forward by its angular velocity.
+            current_rotation
+                + current_velocity.unwrap_or(0.0)
+                    * extrapolation_time.min(max_time.adjust_precision())
         }
+    };
+    rotation_value_to_quaternion(angle)
+}
 
-        #[cfg(feature = "3d")]
-        if interp_position.pass_raw || interp_position.previous_position == None {
-            // Use the current position of the physics object directly without interpolating.
-            transform.translation = current_position.0
-        } else {
-            // Interpolate between the previous and current position of the physics object.
-            let lerp_factor = overstep / delta;
-            if let Some(previous_position) = interp_position.previous_position {
-                transform.translation = previous_position.lerp(current_position.0, lerp_factor);
+/// Computes the rendered rotation in world space from the cached and current state of the source according to `mode`.
+/// `previous_rotation` is expected to already be checked against the snap angle.
+#[cfg(feature = "3d")]
+fn blend_rotation(
+    mode: InterpolationMode,
+    previous_rotation: Option<RotationValue>,
+    previous_velocity: Option<AngularVelocityValue>,
+    current_rotation: RotationValue,
+    current_velocity: Option<AngularVelocityValue>,
+    timing: BlendTiming,
+) -> Quaternion {
+    let (delta, lerp_factor, extrapolation_time) = timing.adjusted();
+    match mode {
+        InterpolationMode::Interpolate => {
+            // Interpolate between the previous and current rotation of the physics object.
+            previous_rotation.map_or(current_rotation, |previous_rotation| {
+                previous_rotation.slerp(current_rotation, lerp_factor)
+            })
+        }
+        InterpolationMode::Hermite => {
+            match (previous_rotation, previous_velocity, current_velocity) {
+                (Some(previous_rotation), Some(previous_velocity), Some(current_velocity)) => {
+                    // Squad-style cubic Bezier in rotation space, equivalent to a Hermite curve with the
+                    // angular velocities scaled to the time-step as tangents. Evaluated using De Casteljau's algorithm.
+                    let control_a = Quaternion::from_scaled_axis(previous_velocity * delta / 3.0)
+                        * previous_rotation;
+                    let control_b = Quaternion::from_scaled_axis(-current_velocity * delta / 3.0)
+                        * current_rotation;
+                    let q0 = previous_rotation.slerp(control_a, lerp_factor);
+                    let q1 = control_a.slerp(control_b, lerp_factor);
+                    let q2 = control_b.slerp(current_rotation, lerp_factor);
+                    let r0 = q0.slerp(q1, lerp_factor);
+                    let r1 = q1.slerp(q2, lerp_factor);
+                    r0.slerp(r1, lerp_factor).normalize()
+                }
+                (Some(previous_rotation), ..) => {
+                    previous_rotation.slerp(current_rotation, lerp_factor)
+                }
+                (None, ..) => current_rotation,
             }
         }
+        InterpolationMode::Extrapolate { max_time } => {
+            // Rotate the current rotation of the physics object forward by its angular velocity.
+            let extrapolation = Quaternion::from_scaled_axis(
+                current_velocity.unwrap_or(Vector::ZERO)
+                    * extrapolation_time.min(max_time.adjust_precision()),
+            );
+            (extrapolation * current_rotation).normalize()
+        }
     }
 }
 
+/// Performs position interpolation and stores the result in the `Transform` of the entity with the `InterpolatedPosition`.
+/// Only the translation is written, and in 2d the z coordinate is left untouched so it can be used for draw ordering.
+/// Runs in `InterpolationSet::Interpolation`.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn interpolate_position(
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedPosition,
+        Option<&InterpolatedRotation>,
+        Option<&Parent>,
+    )>,
+    source_q: Query<(
+        &Position,
+        Option<&LinearVelocity>,
+        Option<&Rotation>,
+        Has<Sleeping>,
+        Option<&RigidBody>,
+    )>,
+    timing: Res<InterpolationTiming>,
+    settings: Res<InterpolationSettings>,
+    par_commands: ParallelCommands,
+    batching: Res<InterpolationBatching>,
+    origin: Res<InterpolationOrigin>,
+) {
+    interp_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(
+            |(entity, mut transform, mut interp_position, interp_rotation, parent)| {
+                let Ok((current_position, linear_velocity, current_rotation, sleeping, rigid_body)) =
+                    source_q.get(interp_position.source)
+                else {
+                    // The source entity must exist and have a `Position` component.
+                    if !interp_position.source_missing {
+                        interp_position.source_missing = true;
+                        par_commands.command_scope(|mut commands| {
+                            handle_missing_source::<InterpolatedPosition>(
+                                &mut commands,
+                                entity,
+                                interp_position.source,
+                                interp_position.missing_source_policy,
+                            );
+                        });
+                    }
+                    return;
+                };
+                if interp_position.source_missing {
+                    interp_position.source_missing = false;
+                }
+
+                // Sleeping and static bodies don't move, so their current position is used directly.
+                let at_rest = sleeping || rigid_body.is_some_and(RigidBody::is_static);
+                let mut position = match timing.0 {
+                    Some(timing) if !interp_position.pass_raw && !at_rest => {
+                        // Treat jumps larger than the snap distance as teleports and ignore the cached position.
+                        let previous_position =
+                            interp_position.previous_position.filter(|previous| {
+                                interp_position.snap_distance.map_or(true, |snap| {
+                                    previous.distance(current_position.0) <= snap
+                                })
+                            });
+                        blend_position(
+                            interp_position.mode.unwrap_or(settings.default_mode),
+                            previous_position,
+                            interp_position.previous_linear_velocity,
+                            current_position.0,
+                            linear_velocity.map(|v| v.0),
+                            timing,
+                        )
+                    }
+                    // Use the current position of the physics object directly without interpolating.
+                    _ => current_position.0,
+                };
+
+                if interp_position.offset != Vector::ZERO {
+                    // Rotate the offset along with the source. `interpolate_rotation` runs first, so the interpolated rotation
+                    // can be recovered from the `Transform`, or from the pending world space rotation if there is a parent.
+                    let source_rotation = if let Some(interp_rotation) = interp_rotation {
+                        let rotation = interp_rotation.pending_rotation.unwrap_or(transform.rotation);
+                        rotation.adjust_precision() * interp_rotation.offset_quat().inverse()
+                    } else {
+                        current_rotation.map_or(Quaternion::IDENTITY, |rotation| {
+                            rotation_value_to_quaternion(rotation_value(rotation))
+                        })
+                    };
+                    position += rotate_offset(source_rotation, interp_position.offset);
+                }
+
+                if parent.is_some() {
+                    // Interpolation is done in world space, the parent may not be interpolated yet.
+                    interp_position.pending_translation = Some(position - origin.0);
+                    return;
+                }
+                // Only write the `Transform` if it changes, to avoid triggering change detection and transform propagation.
+                let mut new_transform = *transform;
+                set_world_translation(&mut new_transform, None, position - origin.0);
+                transform.set_if_neq(new_transform);
+
+                if interp_position.source == entity {
+                    // Remember the written value so the physics position can be restored before the next physics update.
+                    interp_position.in_place_translation = Some(new_transform.translation);
+                }
+            },
+        );
+}
+
 /// Performs rotation interpolation and stores the result in the `Transform` of the entity with the `InterpolatedRotation`.
+/// Only the rotation is written.
 /// Runs in `InterpolationSet::Interpolation`.
+#[allow(clippy::type_complexity)]
 fn interpolate_rotation(
-    mut interp_q: Query<(&mut Transform, &InterpolatedRotation)>,
-    source_q: Query<&Rotation>,
-    phys_time: Res<Time<Physics>>,
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedRotation,
+        Option<&Parent>,
+    )>,
+    source_q: Query<(
+        &Rotation,
+        Option<&AngularVelocity>,
+        Has<Sleeping>,
+        Option<&RigidBody>,
+    )>,
+    timing: Res<InterpolationTiming>,
+    settings: Res<InterpolationSettings>,
+    par_commands: ParallelCommands,
+    batching: Res<InterpolationBatching>,
 ) {
-    // Get the physics time-step
-    let (delta, overstep) = match phys_time.timestep_mode() {
-        TimestepMode::Fixed {
-            delta, overstep, ..
-        } => (delta.as_secs_f32(), overstep.as_secs_f32()),
-        _ => {
-            warn!("The 'PhysicsTimestep' resource does not hold the 'Fixed' variant. Cannot interpolate.");
-            return;
-        }
-    };
+    interp_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|(entity, mut transform, mut interp_rotation, parent)| {
+            let Ok((current_rotation, angular_velocity, sleeping, rigid_body)) =
+                source_q.get(interp_rotation.source)
+            else {
+                // The source entity must exist and have a `Rotation` component.
+                if !interp_rotation.source_missing {
+                    interp_rotation.source_missing = true;
+                    par_commands.command_scope(|mut commands| {
+                        handle_missing_source::<InterpolatedRotation>(
+                            &mut commands,
+                            entity,
+                            interp_rotation.source,
+                            interp_rotation.missing_source_policy,
+                        );
+                    });
+                }
+                return;
+            };
+            if interp_rotation.source_missing {
+                interp_rotation.source_missing = false;
+            }
+
+            let current_rotation = rotation_value(current_rotation);
+            // Sleeping and static bodies don't move, so their current rotation is used directly.
+            let at_rest = sleeping || rigid_body.is_some_and(RigidBody::is_static);
+            let rotation = match timing.0 {
+                Some(timing) if !interp_rotation.pass_raw && !at_rest => {
+                    // Treat jumps larger than the snap angle as teleports and ignore the cached rotation.
+                    let previous_rotation = interp_rotation.previous_rotation.filter(|previous| {
+                        interp_rotation.snap_angle.map_or(true, |snap| {
+                            angle_between(*previous, current_rotation) <= snap
+                        })
+                    });
+                    blend_rotation(
+                        interp_rotation.mode.unwrap_or(settings.default_mode),
+                        previous_rotation,
+                        interp_rotation.previous_angular_velocity,
+                        current_rotation,
+                        angular_velocity.map(|v| v.0),
+                        timing,
+                    )
+                }
+                // Use the current rotation of the physics object directly without interpolating.
+                _ => rotation_value_to_quaternion(current_rotation),
+            };
 
-    for (mut transform, interp_rotation) in interp_q.iter_mut() {
-        let current_rotation = match source_q.get(interp_rotation.source) {
-            Ok(val) => val,
-            Err(_) => {
-                warn!("Invalid source entity for InterpolatedRotation. The source entity must have a Rotation component.");
-                continue;
+            let rotation = (rotation * interp_rotation.offset_quat()).f32();
+            if parent.is_some() {
+                // Interpolation is done in world space, the parent may not be interpolated yet.
+                interp_rotation.pending_rotation = Some(rotation);
+                return;
+            }
+            // Only write the `Transform` if it changes, to avoid triggering change detection and transform propagation.
+            if transform.rotation != rotation {
+                transform.rotation = rotation;
             }
-        };
 
-        #[cfg(feature = "2d")]
-        if interp_rotation.pass_raw || interp_rotation.previous_rotation == None {
-            // Use the current rotation of the physics object directly without interpolating.
-            transform.rotation = Quat::from(*current_rotation);
-            // info!("{}", current_rotation.as_radians());
-        } else {
-            // Interpolate between the previous and current rotation of the physics object.
-            let lerp_factor = overstep / delta;
-            if let Some(previous_rotation) = interp_rotation.previous_rotation {
-                let delta = current_rotation.as_radians() - previous_rotation;
-                // Angles are kept between -pi and pi by bevy_xpbd,
-                // and this makes sure we are going the correct way when rotating between the second and third quadrant.
-                let interpolated_angle = if delta.abs() <= PI {
-                    previous_rotation + lerp_factor * delta
-                } else {
-                    previous_rotation
-                        - (delta / delta.abs()) * lerp_factor * (2.0 * PI - delta.abs())
-                };
+            if interp_rotation.source == entity {
+                // Remember the written value so the physics rotation can be restored before the next physics update.
+                interp_rotation.in_place_rotation = Some(rotation);
+            }
+        });
+}
+
+/// Performs position and rotation interpolation and stores the result in the `Transform` of the entity with the `InterpolatedTransform`.
+/// Only the translation and rotation are written, and in 2d the z coordinate is left untouched so it can be used for draw ordering.
+/// Runs in `InterpolationSet::Interpolation`.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn interpolate_transform(
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedTransform,
+        Option<&Parent>,
+    )>,
+    source_q: Query<(
+        &Position,
+        &Rotation,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+        Has<Sleeping>,
+        Option<&RigidBody>,
+    )>,
+    timing: Res<InterpolationTiming>,
+    settings: Res<InterpolationSettings>,
+    par_commands: ParallelCommands,
+    batching: Res<InterpolationBatching>,
+    origin: Res<InterpolationOrigin>,
+) {
+    interp_q
+        .par_iter_mut()
+        .batching_strategy(batching.0.clone())
+        .for_each(|(entity, mut transform, mut interp, parent)| {
+            let Ok((
+                current_position,
+                current_rotation,
+                linear_velocity,
+                angular_velocity,
+                sleeping,
+                rigid_body,
+            )) = source_q.get(interp.source)
+            else {
+                // The source entity must exist and have `Position` and `Rotation` components.
+                if !interp.source_missing {
+                    interp.source_missing = true;
+                    par_commands.command_scope(|mut commands| {
+                        handle_missing_source::<InterpolatedTransform>(
+                            &mut commands,
+                            entity,
+                            interp.source,
+                            interp.missing_source_policy,
+                        );
+                    });
+                }
+                return;
+            };
+            if interp.source_missing {
+                interp.source_missing = false;
+            }
 
-                transform.rotation = Quat::from(Rotation::from_radians(interpolated_angle));
+            let current_rotation = rotation_value(current_rotation);
+            // Sleeping and static bodies don't move, so their current pose is used directly.
+            let at_rest = sleeping || rigid_body.is_some_and(RigidBody::is_static);
+            let (mut position, rotation) = match timing.0 {
+                Some(timing) if !interp.pass_raw && !at_rest => {
+                    // Treat jumps larger than the snap distance or angle as teleports and ignore the cached values.
+                    let previous_position = interp.previous_position.filter(|previous| {
+                        interp
+                            .snap_distance
+                            .map_or(true, |snap| previous.distance(current_position.0) <= snap)
+                    });
+                    let previous_rotation = interp.previous_rotation.filter(|previous| {
+                        interp.snap_angle.map_or(true, |snap| {
+                            angle_between(*previous, current_rotation) <= snap
+                        })
+                    });
+                    (
+                        blend_position(
+                            interp.mode.unwrap_or(settings.default_mode),
+                            previous_position,
+                            interp.previous_linear_velocity,
+                            current_position.0,
+                            linear_velocity.map(|v| v.0),
+                            timing,
+                        ),
+                        blend_rotation(
+                            interp.mode.unwrap_or(settings.default_mode),
+                            previous_rotation,
+                            interp.previous_angular_velocity,
+                            current_rotation,
+                            angular_velocity.map(|v| v.0),
+                            timing,
+                        ),
+                    )
+                }
+                // Use the current position and rotation of the physics object directly without interpolating.
+                _ => (
+                    current_position.0,
+                    rotation_value_to_quaternion(current_rotation),
+                ),
+            };
+
+            // The translation offset rotates along with the interpolated rotation.
+            position += rotate_offset(rotation, interp.position_offset);
+
+            let rotation = (rotation * interp.rotation_offset_quat()).f32();
+            if parent.is_some() {
+                // Interpolation is done in world space, the parent may not be interpolated yet.
+                interp.pending_translation = Some(position - origin.0);
+                interp.pending_rotation = Some(rotation);
+                return;
+            }
+            // Only write the `Transform` if it changes, to avoid triggering change detection and transform propagation.
+            let mut new_transform = *transform;
+            set_world_translation(&mut new_transform, None, position - origin.0);
+            new_transform.rotation = rotation;
+            transform.set_if_neq(new_transform);
+
+            if interp.source == entity {
+                // Remember the written values so the physics pose can be restored before the next physics update.
+                interp.in_place_translation = Some(new_transform.translation);
+                interp.in_place_rotation = Some(new_transform.rotation);
+            }
+        });
+}
+
+/// Writes the interpolated poses of entities with a parent into their `Transform`, converted into the local space of the parent.
+/// The world space pose of the parent is composed from the `Transform`s up the hierarchy rather than taken from its `GlobalTransform`,
+/// which was propagated last frame. Parents are written before their children, so an interpolated parent is already at its pose for this frame.
+/// Runs in `InterpolationSet::Interpolation`, after the interpolation systems.
+#[allow(clippy::type_complexity)]
+fn write_parented_poses(
+    mut transform_q: Query<&mut Transform>,
+    parent_q: Query<&Parent>,
+    mut position_q: Query<(Entity, &mut InterpolatedPosition), With<Parent>>,
+    mut rotation_q: Query<(Entity, &mut InterpolatedRotation), With<Parent>>,
+    mut interp_q: Query<(Entity, &mut InterpolatedTransform), With<Parent>>,
+    mut snapshot_q: Query<(Entity, &mut crate::snapshot::SnapshotInterpolated), With<Parent>>,
+    mut pending: Local<Vec<(usize, Entity)>>,
+) {
+    // Order the entities by their depth in the hierarchy, so ancestors are written first.
+    pending.clear();
+    let depth = |entity: Entity| parent_q.iter_ancestors(entity).count();
+    pending.extend(
+        position_q
+            .iter()
+            .filter(|(_, interp)| interp.pending_translation.is_some())
+            .map(|(entity, _)| (depth(entity), entity)),
+    );
+    pending.extend(
+        rotation_q
+            .iter()
+            .filter(|(_, interp)| interp.pending_rotation.is_some())
+            .map(|(entity, _)| (depth(entity), entity)),
+    );
+    pending.extend(
+        interp_q
+            .iter()
+            .filter(|(_, interp)| interp.pending_translation.is_some())
+            .map(|(entity, _)| (depth(entity), entity)),
+    );
+    pending.extend(
+        snapshot_q
+            .iter()
+            .filter(|(_, interp)| interp.pending_pose.is_some())
+            .map(|(entity, _)| (depth(entity), entity)),
+    );
+    pending.sort_unstable();
+    pending.dedup();
+
+    for &(_, entity) in pending.iter() {
+        let parent = parent_q
+            .iter_ancestors(entity)
+            .filter_map(|ancestor| transform_q.get(ancestor).ok())
+            .fold(GlobalTransform::IDENTITY, |pose, transform| {
+                GlobalTransform::from(*transform) * pose
+            });
+        let Ok(mut transform) = transform_q.get_mut(entity) else {
+            continue;
+        };
+        let mut new_transform = *transform;
+
+        if let Ok((_, mut interp)) = position_q.get_mut(entity) {
+            if let Some(position) = interp.pending_translation.take() {
+                set_world_translation(&mut new_transform, Some(&parent), position);
+                if interp.source == entity {
+                    interp.in_place_translation = Some(new_transform.translation);
+                }
+            }
+        }
+        if let Ok((_, mut interp)) = rotation_q.get_mut(entity) {
+            if let Some(rotation) = interp.pending_rotation.take() {
+                new_transform.rotation = world_to_local_rotation(Some(&parent), rotation);
+                if interp.source == entity {
+                    interp.in_place_rotation = Some(new_transform.rotation);
+                }
+            }
+        }
+        if let Ok((_, mut interp)) = interp_q.get_mut(entity) {
+            if let (Some(position), Some(rotation)) = (
+                interp.pending_translation.take(),
+                interp.pending_rotation.take(),
+            ) {
+                set_world_translation(&mut new_transform, Some(&parent), position);
+                new_transform.rotation = world_to_local_rotation(Some(&parent), rotation);
+                if interp.source == entity {
+                    interp.in_place_translation = Some(new_transform.translation);
+                    interp.in_place_rotation = Some(new_transform.rotation);
+                }
+            }
+        }
+        if let Ok((_, mut interp)) = snapshot_q.get_mut(entity) {
+            if let Some((position, rotation)) = interp.pending_pose.take() {
+                set_world_translation(&mut new_transform, Some(&parent), position);
+                new_transform.rotation = world_to_local_rotation(Some(&parent), rotation);
             }
         }
 
-        #[cfg(feature = "3d")]
-        if interp_rotation.pass_raw || interp_rotation.previous_rotation == None {
-            // Use the current rotation of the physics object directly without interpolating.
-            transform.rotation = current_rotation.0;
-        } else {
-            // Interpolate between the previous and current rotation of the physics object.
-            let lerp_factor = overstep / delta;
-            if let Some(previous_rotation) = interp_rotation.previous_rotation {
-                transform.rotation = previous_rotation.slerp(current_rotation.0, lerp_factor);
+        // Only write the `Transform` if it changes, to avoid triggering change detection and transform propagation.
+        transform.set_if_neq(new_transform);
+    }
+}
+
+/// The reader of `PhysicsOriginShifted` events shared by both runs of `shift_previous_positions`, so every shift is applied once.
+#[derive(Resource, Default)]
+pub(crate) struct OriginShiftReader(ManualEventReader<PhysicsOriginShifted>);
+
+/// Moves the cached previous positions along with the physics world when it is re-centered.
+/// Runs before `InterpolationCopySet`, so a physics step in the same frame caches its state in the new frame,
+/// and every frame before `InterpolationSet::Interpolation`, so frames without a physics step blend in the new frame as well.
+/// Whichever runs first after the shift applies it.
+fn shift_previous_positions(
+    mut reader: ResMut<OriginShiftReader>,
+    shifts: Res<Events<PhysicsOriginShifted>>,
+    mut interp_position_q: Query<&mut InterpolatedPosition>,
+    mut interp_transform_q: Query<&mut InterpolatedTransform>,
+) {
+    let translation = reader
+        .0
+        .read(&shifts)
+        .fold(Vector::ZERO, |sum, shift| sum + shift.translation);
+    if translation == Vector::ZERO {
+        return;
+    }
+
+    for mut interp in interp_position_q.iter_mut() {
+        if let Some(previous_position) = &mut interp.previous_position {
+            *previous_position += translation;
+        }
+    }
+    for mut interp in interp_transform_q.iter_mut() {
+        if let Some(previous_position) = &mut interp.previous_position {
+            *previous_position += translation;
+        }
+    }
+}
+
+/// Restores the physics position to the `Transform` of every entity that is its own `InterpolatedPosition` source,
+/// so the interpolated value is never picked up as a `Transform` change by the physics engine.
+/// If the `Transform` was changed by something else since the interpolation it is left untouched.
+/// Runs in `PostUpdate` before `PhysicsSet::Prepare`.
+fn restore_position(
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedPosition,
+        &Position,
+        Option<&Parent>,
+    )>,
+    parent_q: Query<&GlobalTransform>,
+) {
+    for (entity, mut transform, mut interp_position, position, parent) in interp_q.iter_mut() {
+        if interp_position.source != entity {
+            continue;
+        }
+
+        if interp_position.in_place_translation.take() == Some(transform.translation) {
+            let parent_transform = parent.and_then(|parent| parent_q.get(parent.get()).ok());
+            let mut new_transform = *transform;
+            set_world_translation(&mut new_transform, parent_transform, position.0);
+            transform.set_if_neq(new_transform);
+        }
+    }
+}
+
+/// Restores the physics rotation to the `Transform` of every entity that is its own `InterpolatedRotation` source,
+/// so the interpolated value is never picked up as a `Transform` change by the physics engine.
+/// If the `Transform` was changed by something else since the interpolation it is left untouched.
+/// Runs in `PostUpdate` before `PhysicsSet::Prepare`.
+fn restore_rotation(
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedRotation,
+        &Rotation,
+        Option<&Parent>,
+    )>,
+    parent_q: Query<&GlobalTransform>,
+) {
+    for (entity, mut transform, mut interp_rotation, rotation, parent) in interp_q.iter_mut() {
+        if interp_rotation.source != entity {
+            continue;
+        }
+
+        if interp_rotation.in_place_rotation.take() == Some(transform.rotation) {
+            let parent_transform = parent.and_then(|parent| parent_q.get(parent.get()).ok());
+            let rotation = world_to_local_rotation(
+                parent_transform,
+                rotation_value_to_quat(rotation_value(rotation)),
+            );
+            if transform.rotation != rotation {
+                transform.rotation = rotation;
             }
         }
     }
 }
+
+/// Restores the physics pose to the `Transform` of every entity that is its own `InterpolatedTransform` source,
+/// so the interpolated values are never picked up as a `Transform` change by the physics engine.
+/// If the `Transform` was changed by something else since the interpolation it is left untouched.
+/// Runs in `PostUpdate` before `PhysicsSet::Prepare`.
+#[allow(clippy::type_complexity)]
+fn restore_transform(
+    mut interp_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut InterpolatedTransform,
+        &Position,
+        &Rotation,
+        Option<&Parent>,
+    )>,
+    parent_q: Query<&GlobalTransform>,
+) {
+    for (entity, mut transform, mut interp, position, rotation, parent) in interp_q.iter_mut() {
+        if interp.source != entity {
+            continue;
+        }
+
+        let parent_transform = parent.and_then(|parent| parent_q.get(parent.get()).ok());
+        let mut new_transform = *transform;
+        if interp.in_place_translation.take() == Some(transform.translation) {
+            set_world_translation(&mut new_transform, parent_transform, position.0);
+        }
+        if interp.in_place_rotation.take() == Some(transform.rotation) {
+            new_transform.rotation = world_to_local_rotation(
+                parent_transform,
+                rotation_value_to_quat(rotation_value(rotation)),
+            );
+        }
+        transform.set_if_neq(new_transform);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELTA: f32 = 0.125;
+    const EPSILON: Scalar = 1e-4;
+
+    fn timing(lerp_factor: f32) -> BlendTiming {
+        BlendTiming {
+            delta: DELTA,
+            lerp_factor,
+            extrapolation_time: 0.0,
+        }
+    }
+
+    /// The lerp factors at which the curves are sampled, from 0 to 1.
+    fn factors() -> impl Iterator<Item = f32> {
+        (0..=10).map(|i| i as f32 / 10.0)
+    }
+
+    #[test]
+    fn hermite_position_hits_endpoints() {
+        let previous = Vector::X;
+        let current = Vector::Y * 2.0;
+        let velocity = Some(Vector::X * 3.0 - Vector::Y);
+        for (factor, expected) in [(0.0, previous), (1.0, current)] {
+            let position = blend_position(
+                InterpolationMode::Hermite,
+                Some(previous),
+                velocity,
+                current,
+                velocity,
+                timing(factor),
+            );
+            assert!(
+                position.distance(expected) < EPSILON,
+                "{position} at {factor}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn hermite_position_keeps_constant_velocity_straight() {
+        let velocity = Vector::X * 2.0 + Vector::Y;
+        let previous = Vector::Y;
+        let current = previous + velocity * DELTA.adjust_precision();
+        for factor in factors() {
+            let position = blend_position(
+                InterpolationMode::Hermite,
+                Some(previous),
+                Some(velocity),
+                current,
+                Some(velocity),
+                timing(factor),
+            );
+            // On the line between both positions, and moving along it at a constant speed.
+            let expected = previous.lerp(current, factor.adjust_precision());
+            assert!(
+                position.distance(expected) < EPSILON,
+                "{position} at {factor}, expected {expected}"
+            );
+        }
+    }
+
+    #[cfg(feature = "2d")]
+    #[test]
+    fn hermite_angle_wraps_around_pi() {
+        // From just below pi to just above -pi, the short way is across pi rather than through 0.
+        let previous = 3.0;
+        let current = -3.0;
+        let arc = 2.0 * PI - 6.0;
+        let velocity = Some(arc / DELTA.adjust_precision());
+        for factor in factors() {
+            let rotation = blend_rotation(
+                InterpolationMode::Hermite,
+                Some(previous),
+                velocity,
+                current,
+                velocity,
+                timing(factor),
+            );
+            let expected = rotation_value_to_quaternion(previous + arc * factor.adjust_precision());
+            assert!(
+                rotation.abs_diff_eq(expected, EPSILON),
+                "{rotation} at {factor}, expected {expected}"
+            );
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    #[test]
+    fn hermite_rotation_hits_endpoints() {
+        let previous = Quaternion::from_rotation_x(0.5);
+        let current = Quaternion::from_rotation_y(1.0) * previous;
+        let previous_velocity = Some(Vector::new(0.5, 4.0, 0.0));
+        let current_velocity = Some(Vector::new(0.0, 8.0, -1.0));
+        for (factor, expected) in [(0.0, previous), (1.0, current)] {
+            let rotation = blend_rotation(
+                InterpolationMode::Hermite,
+                Some(previous),
+                previous_velocity,
+                current,
+                current_velocity,
+                timing(factor),
+            );
+            assert!(
+                rotation.angle_between(expected) < EPSILON,
+                "{rotation} at {factor}, expected {expected}"
+            );
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    #[test]
+    fn hermite_rotation_is_normalized_between_endpoints() {
+        // Turning 1 radian around y at a constant angular velocity.
+        let previous = Quaternion::from_rotation_x(0.5);
+        let current = Quaternion::from_rotation_y(1.0) * previous;
+        let velocity = Some(Vector::Y / DELTA.adjust_precision());
+        for factor in factors() {
+            let rotation = blend_rotation(
+                InterpolationMode::Hermite,
+                Some(previous),
+                velocity,
+                current,
+                velocity,
+                timing(factor),
+            );
+            assert!(rotation.is_normalized(), "{rotation} at {factor}");
+            let from_previous = rotation.angle_between(previous);
+            let to_current = rotation.angle_between(current);
+            assert!(
+                (from_previous - factor.adjust_precision()).abs() < EPSILON
+                    && (from_previous + to_current - 1.0).abs() < EPSILON,
+                "{rotation} at {factor} is {from_previous} from the previous and {to_current} from the current rotation"
+            );
+        }
+    }
+}
@@ -1,28 +1,202 @@
 //! Sets up the XPBDInterplationPlugin.
 
-use bevy::{prelude::*, transform::TransformSystem};
+use bevy::{
+    ecs::{
+        query::BatchingStrategy,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::*,
+    transform::TransformSystem,
+};
 #[cfg(feature = "2d")]
-use bevy_xpbd_2d::{prelude::*, PhysicsSchedule, PhysicsStepSet};
+use bevy_xpbd_2d::{
+    math::{Quaternion, Scalar, Vector},
+    prelude::*,
+    PhysicsSchedule, PhysicsStepSet,
+};
 #[cfg(feature = "3d")]
-use bevy_xpbd_3d::{prelude::*, PhysicsSchedule, PhysicsStepSet};
+use bevy_xpbd_3d::{
+    math::{Quaternion, Scalar, Vector},
+    prelude::*,
+    PhysicsSchedule, PhysicsStepSet,
+};
 
 use crate::prelude::*;
 
-pub struct XPBDInterpolationPlugin;
+/// Sets up interpolation of physics objects.
+/// By default the physics state is cached in `PhysicsSchedule` and interpolated in `PostUpdate`, matching `PhysicsPlugins::default()`.
+/// If physics runs in another schedule, such as `FixedPostUpdate`, interpolation should still run every frame.
+pub struct XPBDInterpolationPlugin {
+    // The schedule `PhysicsPlugins` runs in
+    physics_schedule: InternedScheduleLabel,
+    // The schedule `InterpolationCopySet` runs in
+    copy_schedule: InternedScheduleLabel,
+    // The schedule `InterpolationSet` runs in
+    interpolation_schedule: InternedScheduleLabel,
+    default_mode: InterpolationMode,
+    enabled: bool,
+    register_reflection: bool,
+    batching_strategy: BatchingStrategy,
+}
+
+impl XPBDInterpolationPlugin {
+    /// `schedule` should be the schedule given to `PhysicsPlugins::new`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            physics_schedule: schedule.intern(),
+            copy_schedule: PhysicsSchedule.intern(),
+            interpolation_schedule: PostUpdate.intern(),
+            default_mode: InterpolationMode::default(),
+            enabled: true,
+            register_reflection: true,
+            batching_strategy: BatchingStrategy::new(),
+        }
+    }
+
+    /// Caches the physics state in `schedule` instead of `PhysicsSchedule`.
+    /// It should run once before every physics step. The physics state is cached at most once per step,
+    /// and if more than one step completes between two runs of `schedule` the physics pose is rendered without interpolation.
+    pub fn with_copy_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.copy_schedule = schedule.intern();
+        self
+    }
+
+    /// Interpolates in `schedule` instead of `PostUpdate`.
+    /// It should run once every frame after physics and before transforms are propagated.
+    pub fn with_interpolation_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.interpolation_schedule = schedule.intern();
+        self
+    }
+
+    /// The mode used by interpolation components that do not set their own.
+    pub fn with_default_mode(mut self, mode: InterpolationMode) -> Self {
+        self.default_mode = mode;
+        self
+    }
+
+    /// If disabled the physics state is rendered without interpolation until enabled in the `InterpolationSettings`.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// How interpolated entities are split into batches to be processed in parallel.
+    /// Larger batches have less overhead, smaller batches spread the work over more threads.
+    pub fn with_batching_strategy(mut self, batching_strategy: BatchingStrategy) -> Self {
+        self.batching_strategy = batching_strategy;
+        self
+    }
+
+    /// Whether to register the types of this crate for reflection.
+    pub fn with_reflection(mut self, register_reflection: bool) -> Self {
+        self.register_reflection = register_reflection;
+        self
+    }
+}
+
+impl Default for XPBDInterpolationPlugin {
+    fn default() -> Self {
+        Self::new(PostUpdate)
+    }
+}
 
 impl Plugin for XPBDInterpolationPlugin {
     fn build(&self, app: &mut App) {
+        app.add_event::<InterpolationSourceMissing>()
+            .add_event::<PhysicsOriginShifted>()
+            .init_resource::<InterpolationOrigin>()
+            .init_resource::<crate::InterpolationTiming>()
+            .init_resource::<crate::PhysicsSteps>()
+            .insert_resource(InterpolationSettings {
+                enabled: self.enabled,
+                default_mode: self.default_mode,
+            })
+            .insert_resource(InterpolationBatching(self.batching_strategy.clone()))
+            .insert_resource(crate::InterpolationSchedules {
+                physics: self.physics_schedule,
+                copy: self.copy_schedule,
+                interpolate: self.interpolation_schedule,
+            });
+
+        if self.register_reflection {
+            app.register_type::<InterpolatedPosition>()
+                .register_type::<InterpolatedRotation>()
+                .register_type::<InterpolatedTransform>()
+                .register_type::<Interpolated>()
+                .register_type::<InterpolationRenderEntity>()
+                .register_type::<InterpolationMode>()
+                .register_type::<MissingSourcePolicy>()
+                .register_type::<InterpolationSettings>()
+                .register_type::<InterpolationOrigin>()
+                .register_type::<SnapshotInterpolated>()
+                .register_type::<PoseSnapshot>()
+                .register_type::<InterpolationCopySet>()
+                .register_type::<InterpolationSet>()
+                // The optional fields of the components, needed to load them from a scene.
+                .register_type::<Option<Vector>>()
+                .register_type::<Option<Scalar>>()
+                .register_type::<Option<Quaternion>>()
+                .register_type::<Option<InterpolationMode>>()
+                .register_type::<Option<f64>>();
+        }
+
+        // The physics state is cached once per physics step, tracked by counting the steps run in `PhysicsSchedule`.
         app.configure_sets(
-            PhysicsSchedule,
-            InterpolationCopySet.before(PhysicsStepSet::BroadPhase),
+            self.copy_schedule,
+            InterpolationCopySet
+                .before(PhysicsStepSet::BroadPhase)
+                .run_if(crate::physics_step_pending),
+        )
+        .add_systems(
+            self.copy_schedule,
+            (
+                (
+                    crate::copy_position,
+                    crate::copy_rotation,
+                    crate::copy_transform,
+                )
+                    .in_set(InterpolationCopySet),
+                crate::mark_step_copied
+                    .after(InterpolationCopySet)
+                    .before(PhysicsStepSet::BroadPhase),
+            ),
         )
         .add_systems(
             PhysicsSchedule,
-            (crate::copy_position, crate::copy_rotation).in_set(InterpolationCopySet),
+            crate::complete_physics_step.after(PhysicsStepSet::SpatialQuery),
+        );
+
+        // Entities interpolated in place need their physics pose back before the physics engine reads their `Transform`.
+        app.add_systems(
+            self.physics_schedule,
+            (
+                crate::restore_position,
+                crate::restore_rotation,
+                crate::restore_transform,
+            )
+                .before(PhysicsSet::Prepare),
         );
 
+        app.add_systems(
+            self.physics_schedule,
+            (crate::spawn_render_entities, crate::despawn_render_entities)
+                .before(PhysicsSet::Prepare),
+        );
+
+        // Cached positions are moved with the world before the next physics step caches positions in the new frame,
+        // or before interpolating if no physics step runs in between, as physics may not run every frame.
+        app.init_resource::<crate::OriginShiftReader>()
+            .add_systems(
+                self.copy_schedule,
+                crate::shift_previous_positions.before(InterpolationCopySet),
+            )
+            .add_systems(
+                self.interpolation_schedule,
+                crate::shift_previous_positions.before(InterpolationSet::Interpolation),
+            );
+
         app.configure_sets(
-            PostUpdate,
+            self.interpolation_schedule,
             (
                 InterpolationSet::Interpolation,
                 InterpolationSet::PostInterpolation,
@@ -32,8 +206,19 @@ impl Plugin for XPBDInterpolationPlugin {
                 .before(TransformSystem::TransformPropagate),
         )
         .add_systems(
-            PostUpdate,
-            (crate::interpolate_position, crate::interpolate_rotation)
+            self.interpolation_schedule,
+            // Position offsets rotate with the interpolated rotation, so rotation is interpolated first.
+            // Entities with a parent are written last, once the poses of their parents are known.
+            (
+                crate::update_interpolation_timing,
+                (
+                    (crate::interpolate_rotation, crate::interpolate_position).chain(),
+                    crate::interpolate_transform,
+                    crate::snapshot::interpolate_snapshots,
+                ),
+                crate::write_parented_poses,
+            )
+                .chain()
                 .in_set(InterpolationSet::Interpolation),
         );
     }
@@ -1,5 +1,28 @@
+pub use crate::commands::InterpolationCommandsExt;
+pub use crate::commands::SpawnInterpolatedExt;
+#[cfg(feature = "debug")]
+pub use crate::debug::InterpolationDebugConfig;
+#[cfg(feature = "debug")]
+pub use crate::debug::InterpolationDebugPlugin;
+pub use crate::diagnostics::InterpolationDiagnosticsPlugin;
+pub use crate::interpolate::Interpolate;
+pub use crate::interpolate::InterpolatedComponent;
+pub use crate::interpolate::InterpolationAppExt;
+pub use crate::interpolate::InterpolationTarget;
 pub use crate::plugin::XPBDInterpolationPlugin;
+pub use crate::snapshot::PoseSnapshot;
+pub use crate::snapshot::SnapshotInterpolated;
+pub use crate::Interpolated;
 pub use crate::InterpolatedPosition;
 pub use crate::InterpolatedRotation;
+pub use crate::InterpolatedTransform;
+pub use crate::InterpolationBatching;
 pub use crate::InterpolationCopySet;
+pub use crate::InterpolationMode;
+pub use crate::InterpolationOrigin;
+pub use crate::InterpolationRenderEntity;
 pub use crate::InterpolationSet;
+pub use crate::InterpolationSettings;
+pub use crate::InterpolationSourceMissing;
+pub use crate::MissingSourcePolicy;
+pub use crate::PhysicsOriginShifted;
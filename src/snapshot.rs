@@ -0,0 +1,255 @@
+//! Interpolation of poses received at irregular times, such as network snapshots of remote entities.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use bevy_xpbd_2d::{
+    math::{AdjustPrecision, AsF32, Scalar, Vector},
+    prelude::*,
+};
+#[cfg(feature = "3d")]
+use bevy_xpbd_3d::{
+    math::{AdjustPrecision, AsF32, Scalar, Vector},
+    prelude::*,
+};
+
+use crate::prelude::*;
+
+// How much of the difference between the render time and the time it should be at is corrected for every new snapshot.
+// Small enough that jitter in the arrival of snapshots does not show as stuttering.
+const TIME_CORRECTION: f64 = 0.1;
+
+/// A pose received at some point in time, for example from a server.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct PoseSnapshot {
+    /// When the pose was taken in seconds, on the clock of whoever sent it.
+    pub time: f64,
+    pub position: Vector,
+    pub rotation: Rotation,
+}
+
+impl PoseSnapshot {
+    pub fn new(time: f64, position: Vector, rotation: Rotation) -> Self {
+        Self {
+            time,
+            position,
+            rotation,
+        }
+    }
+}
+
+/// Smooths out poses that arrive at irregular times, such as authoritative snapshots of remote entities in multiplayer.
+/// Received snapshots are kept in a buffer and the `Transform` of the entity is interpolated between them `render_delay` seconds behind the newest one,
+/// so there is usually a newer snapshot to interpolate towards even if snapshots arrive late.
+/// The entity does not need a source, snapshots are added with `push`.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct SnapshotInterpolated {
+    // The received snapshots ordered by time, oldest first.
+    #[reflect(ignore)]
+    snapshots: VecDeque<PoseSnapshot>,
+    // The maximum number of snapshots kept. When full, the oldest snapshot is dropped.
+    pub capacity: usize,
+    // How far behind the newest snapshot to render, in seconds. Should cover the time between snapshots plus the jitter in their arrival.
+    pub render_delay: f64,
+    // How far past the newest snapshot to extrapolate when no newer snapshot arrives in time, in seconds.
+    // After that the entity holds still until the next snapshot.
+    pub max_extrapolation: f64,
+    // If two consecutive snapshots are further apart in time than this they are not interpolated between, and the newer pose is rendered directly.
+    pub max_gap: Option<f64>,
+    // The time on the snapshot clock that is currently rendered.
+    #[reflect(ignore)]
+    render_time: Option<f64>,
+    // The time of the newest snapshot when the render time was last corrected.
+    #[reflect(ignore)]
+    synced_time: Option<f64>,
+    // The interpolated world space pose of an entity with a parent, written by `write_parented_poses` once the parent is interpolated.
+    #[reflect(ignore)]
+    pub(crate) pending_pose: Option<(Vector, Quat)>,
+}
+
+impl Default for SnapshotInterpolated {
+    fn default() -> Self {
+        Self {
+            snapshots: VecDeque::new(),
+            capacity: 32,
+            render_delay: 0.1,
+            max_extrapolation: 0.25,
+            max_gap: None,
+            render_time: None,
+            synced_time: None,
+            pending_pose: None,
+        }
+    }
+}
+
+impl SnapshotInterpolated {
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn with_render_delay(mut self, render_delay: f64) -> Self {
+        self.render_delay = render_delay;
+        self
+    }
+
+    pub fn with_max_extrapolation(mut self, max_extrapolation: f64) -> Self {
+        self.max_extrapolation = max_extrapolation;
+        self
+    }
+
+    pub fn with_max_gap(mut self, max_gap: f64) -> Self {
+        self.max_gap = Some(max_gap);
+        self
+    }
+
+    /// Adds a received snapshot. Snapshots may arrive out of order, a snapshot with the same time as an existing one replaces it.
+    pub fn push(&mut self, snapshot: PoseSnapshot) {
+        let index = self
+            .snapshots
+            .partition_point(|existing| existing.time < snapshot.time);
+        match self.snapshots.get_mut(index) {
+            Some(existing) if existing.time == snapshot.time => *existing = snapshot,
+            _ => self.snapshots.insert(index, snapshot),
+        }
+        while self.snapshots.len() > self.capacity.max(1) {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// The buffered snapshots, oldest first.
+    pub fn snapshots(&self) -> impl Iterator<Item = &PoseSnapshot> {
+        self.snapshots.iter()
+    }
+
+    /// The newest buffered snapshot.
+    pub fn newest(&self) -> Option<&PoseSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Removes all snapshots and restarts the render time from the next snapshot.
+    /// Call this when the entity is teleported or its stream of snapshots restarts.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.render_time = None;
+        self.synced_time = None;
+    }
+
+    /// Advances the render time by `delta` seconds and keeps it `render_delay` behind the newest snapshot.
+    fn advance(&mut self, delta: f64) -> Option<f64> {
+        let newest = self.snapshots.back()?.time;
+        let target = newest - self.render_delay;
+
+        let render_time = match self.render_time {
+            Some(render_time) if self.synced_time == Some(newest) => render_time + delta,
+            Some(render_time) => {
+                // A newer snapshot arrived, so nudge the render time towards where it should be.
+                // If it is off by more than the delay, for example after a gap in the snapshots, jump there instead.
+                let render_time = render_time + delta;
+                let error = target - render_time;
+                if error.abs() > self.render_delay {
+                    target
+                } else {
+                    render_time + error * TIME_CORRECTION
+                }
+            }
+            None => target,
+        };
+        self.synced_time = Some(newest);
+
+        // Don't extrapolate further than allowed, the entity holds still until the next snapshot arrives.
+        let render_time = render_time.min(newest + self.max_extrapolation);
+        self.render_time = Some(render_time);
+
+        // Snapshots older than the one right before the render time are no longer needed.
+        while self.snapshots.len() > 2 && self.snapshots[1].time <= render_time {
+            self.snapshots.pop_front();
+        }
+        Some(render_time)
+    }
+
+    /// Returns the pose at `time`, interpolated between the snapshots around it, or extrapolated from the newest two.
+    fn sample(&self, time: f64) -> Option<(Vector, Quat)> {
+        let newest = self.snapshots.back()?;
+        let index = self
+            .snapshots
+            .partition_point(|snapshot| snapshot.time <= time);
+        let (from, to) = match index {
+            // Before the oldest snapshot, so there is nothing to interpolate from.
+            0 => return Some(pose(&self.snapshots[0])),
+            // Past the newest snapshot, so extrapolate along the motion between the newest two.
+            index if index == self.snapshots.len() => match self.snapshots.len() {
+                1 => return Some(pose(newest)),
+                len => (&self.snapshots[len - 2], newest),
+            },
+            index => (&self.snapshots[index - 1], &self.snapshots[index]),
+        };
+
+        let gap = to.time - from.time;
+        if gap <= 0.0 || self.max_gap.is_some_and(|max_gap| gap > max_gap) {
+            return Some(pose(to));
+        }
+        let t = ((time - from.time) / gap).adjust_precision();
+        Some(blend_pose(from, to, t))
+    }
+}
+
+/// Returns the position and `Transform` rotation of a snapshot.
+fn pose(snapshot: &PoseSnapshot) -> (Vector, Quat) {
+    (
+        snapshot.position,
+        crate::rotation_value_to_quat(crate::rotation_value(&snapshot.rotation)),
+    )
+}
+
+/// Returns the pose `t` of the way from `from` to `to`. `t` may be larger than 1 to extrapolate.
+fn blend_pose(from: &PoseSnapshot, to: &PoseSnapshot, t: Scalar) -> (Vector, Quat) {
+    let position = from.position.lerp(to.position, t);
+    let from_rotation = crate::rotation_value(&from.rotation);
+    let to_rotation = crate::rotation_value(&to.rotation);
+    #[cfg(feature = "2d")]
+    let rotation = from_rotation + t * crate::shortest_angle_delta(from_rotation, to_rotation);
+    #[cfg(feature = "3d")]
+    let rotation = from_rotation.slerp(to_rotation, t);
+    (
+        position,
+        crate::rotation_value_to_quaternion(rotation).f32(),
+    )
+}
+
+/// Advances the render time of every `SnapshotInterpolated` and writes the pose at that time to its `Transform`.
+/// If interpolation is disabled in the `InterpolationSettings`, the newest snapshot is rendered directly.
+/// Runs in `InterpolationSet::Interpolation`.
+pub(crate) fn interpolate_snapshots(
+    mut snapshot_q: Query<(&mut Transform, &mut SnapshotInterpolated, Option<&Parent>)>,
+    time: Res<Time<Real>>,
+    settings: Res<InterpolationSettings>,
+    origin: Res<InterpolationOrigin>,
+) {
+    for (mut transform, mut interp, parent) in snapshot_q.iter_mut() {
+        let Some(render_time) = interp.advance(time.delta_seconds_f64()) else {
+            continue;
+        };
+        let pose = if settings.enabled {
+            interp.sample(render_time)
+        } else {
+            interp.newest().map(pose)
+        };
+        let Some((position, rotation)) = pose else {
+            continue;
+        };
+
+        if parent.is_some() {
+            // The parent may not be interpolated yet, so the pose is written by `write_parented_poses`.
+            interp.pending_pose = Some((position - origin.0, rotation));
+            continue;
+        }
+        // Only write the `Transform` if it changes, to avoid triggering change detection and transform propagation.
+        let mut new_transform = *transform;
+        crate::set_world_translation(&mut new_transform, None, position - origin.0);
+        new_transform.rotation = rotation;
+        transform.set_if_neq(new_transform);
+    }
+}